@@ -1,8 +1,10 @@
 use sc_service::ChainType;
-use solochain_template_runtime::{AccountId, Signature, WASM_BINARY};
+use solochain_template_runtime::{
+	configs::MarketplaceFeeAccount, AccountId, Signature, EXISTENTIAL_DEPOSIT, UNIT, WASM_BINARY,
+};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
-use sp_core::{sr25519, Pair, Public};
+use sp_core::{blake2_256, sr25519, Pair, Public};
 use sp_runtime::traits::{IdentifyAccount, Verify};
 
 // The URL for the telemetry server.
@@ -98,10 +100,23 @@ fn testnet_genesis(
 	endowed_accounts: Vec<AccountId>,
 	_enable_println: bool,
 ) -> serde_json::Value {
+	let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+	let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+	let metadata = |name: &str, uri: &str, genre: &str| {
+		let attributes = vec![(b"genre".to_vec(), genre.as_bytes().to_vec())];
+		Some((name.as_bytes().to_vec(), uri.as_bytes().to_vec(), attributes))
+	};
+
 	serde_json::json!({
 		"balances": {
-			// Configure endowed accounts with initial balance of 1 << 60.
-			"balances": endowed_accounts.iter().cloned().map(|k| (k, 1u64 << 60)).collect::<Vec<_>>(),
+			// Configure endowed accounts with initial balance of 1 << 60. The marketplace fee
+			// account gets the existential deposit so that fees below it can still be paid in.
+			"balances": endowed_accounts
+				.iter()
+				.cloned()
+				.map(|k| (k, 1u64 << 60))
+				.chain(Some((MarketplaceFeeAccount::get(), EXISTENTIAL_DEPOSIT as u64)))
+				.collect::<Vec<_>>(),
 		},
 		"aura": {
 			"authorities": initial_authorities.iter().map(|x| (x.0.clone())).collect::<Vec<_>>(),
@@ -113,5 +128,34 @@ fn testnet_genesis(
 			// Assign network admin rights.
 			"key": Some(root_key),
 		},
+		"gamePallet": {
+			// A few sample games for Alice and Bob, some of them already for sale.
+			"games": [
+				(
+					alice.clone(),
+					blake2_256(b"sample/chess"),
+					Some(10 * UNIT as u64),
+					metadata("Chess", "ipfs://sample/chess", "strategy"),
+				),
+				(
+					alice,
+					blake2_256(b"sample/go"),
+					None::<u64>,
+					metadata("Go", "ipfs://sample/go", "strategy"),
+				),
+				(
+					bob.clone(),
+					blake2_256(b"sample/racer"),
+					Some(5 * UNIT as u64),
+					metadata("Racer", "ipfs://sample/racer", "racing"),
+				),
+				(
+					bob,
+					blake2_256(b"sample/puzzle"),
+					None::<u64>,
+					metadata("Puzzle", "ipfs://sample/puzzle", "puzzle"),
+				),
+			],
+		},
 	})
 }
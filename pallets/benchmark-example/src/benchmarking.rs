@@ -28,5 +28,21 @@ mod benchmarks {
 		mint(RawOrigin::Signed(caller), collection.try_into().unwrap(), asset.try_into().unwrap(), recipient, 10);
 	}
 
+	#[benchmark]
+	fn destroy_collection() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection: BoundedVec<u8, T::MaxAttributeKeySize> =
+			vec![1u8; T::MaxAttributeKeySize::get() as usize].try_into().unwrap();
+		// `destroy_collection` clears at most 10 assets per call.
+		for i in 0..10u8 {
+			let asset: BoundedVec<u8, ConstU32<32>> = vec![i; 32].try_into().unwrap();
+			TotalSupply::<T>::insert(&collection, &asset, 10);
+		}
+		#[extrinsic_call]
+		destroy_collection(RawOrigin::Signed(caller), collection.clone());
+
+		assert_eq!(TotalSupply::<T>::iter_prefix(&collection).count(), 0);
+	}
+
 	impl_benchmark_test_suite!(BenchmarkTemplate, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -13,11 +13,15 @@ use frame_support::pallet_prelude::*;
 use frame_system::pallet_prelude::*;
 pub use pallet::*;
 
-#[frame_support::pallet(dev_mode)]
+#[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(core::marker::PhantomData<T>);
 
 	#[pallet::config]
@@ -126,7 +130,7 @@ pub mod pallet {
 		}
 
         #[pallet::call_index(2)]
-		// #[pallet::weight(<T>::WeightInfo::set_value(0))]
+		#[pallet::weight(<T>::WeightInfo::destroy_collection())]
 		pub fn destroy_collection(
                 origin: OriginFor<T>,
                 collection: BoundedVec<u8, T::MaxAttributeKeySize>,
@@ -1,6 +1,5 @@
 use crate as pallet_benchmark_example;
 use frame_support::derive_impl;
-use sp_runtime::BuildStorage;
 use crate::ConstU32;
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -26,7 +25,10 @@ impl pallet_benchmark_example::Config for Test {
 }
 
 // Build genesis storage according to the mock runtime.
+#[cfg(feature = "runtime-benchmarks")]
 pub fn new_test_ext() -> sp_io::TestExternalities {
+	use sp_runtime::BuildStorage;
+
 	#[allow(clippy::unwrap_used)]
 	frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
 }
@@ -2,14 +2,13 @@
 //! Autogenerated weights for `pallet_benchmark_example`
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 43.0.0
-//! DATE: 2024-11-26, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-18, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `Lohanns-MBP`, CPU: `<UNKNOWN>`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
 
 // Executed Command:
-// ./target/release/solochain-template-node
-// benchmark
+// /root/target-bencher/release/bencher
 // pallet
 // --chain=dev
 // --pallet=pallet_benchmark_example
@@ -33,69 +32,96 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn set_value(x: u32, ) -> Weight;
 	fn mint(x: u32, ) -> Weight;
+	fn destroy_collection() -> Weight;
 }
 
 /// Weights for `pallet_benchmark_example` using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `BenchmarkExample::Value` (r:1 w:1)
-	/// Proof: `BenchmarkExample::Value` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Proof: `BenchmarkExample::Value` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[1, 1000]`.
-	fn set_value(_x: u32, ) -> Weight {
+	fn set_value(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4`
 		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(7_133_164, 1489)
+		// Minimum execution time: 10_200_000 picoseconds.
+		Weight::from_parts(11_802_206, 1489)
+			// Standard Error: 75
+			.saturating_add(Weight::from_parts(102, 0).saturating_mul(x.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `BenchmarkExample::TotalSupply` (r:1 w:1)
-	/// Proof: `BenchmarkExample::TotalSupply` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Proof: `BenchmarkExample::TotalSupply` (`max_values`: None, `max_size`: Some(10071), added: 12546, mode: `MaxEncodedLen`)
 	/// Storage: `BenchmarkExample::Balance` (r:1 w:1)
-	/// Proof: `BenchmarkExample::Balance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Proof: `BenchmarkExample::Balance` (`max_values`: None, `max_size`: Some(10111), added: 12586, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[1, 10000]`.
 	fn mint(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4`
-		//  Estimated: `3469`
-		// Minimum execution time: 11_000_000 picoseconds.
-		Weight::from_parts(11_650_184, 3469)
-			// Standard Error: 10
-			.saturating_add(Weight::from_parts(5_747, 0).saturating_mul(x.into()))
+		//  Estimated: `13576`
+		// Minimum execution time: 18_802_000 picoseconds.
+		Weight::from_parts(21_546_318, 13576)
+			// Standard Error: 99
+			.saturating_add(Weight::from_parts(8_357, 0).saturating_mul(x.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `BenchmarkExample::TotalSupply` (r:10 w:10)
+	/// Proof: `BenchmarkExample::TotalSupply` (`max_values`: None, `max_size`: Some(10071), added: 12546, mode: `MaxEncodedLen`)
+	fn destroy_collection() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10710`
+		//  Estimated: `126450`
+		// Minimum execution time: 197_499_000 picoseconds.
+		Weight::from_parts(209_633_000, 126450)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
 	/// Storage: `BenchmarkExample::Value` (r:1 w:1)
-	/// Proof: `BenchmarkExample::Value` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Proof: `BenchmarkExample::Value` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[1, 1000]`.
-	fn set_value(_x: u32, ) -> Weight {
+	fn set_value(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4`
 		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(7_133_164, 1489)
+		// Minimum execution time: 10_200_000 picoseconds.
+		Weight::from_parts(11_802_206, 1489)
+			// Standard Error: 75
+			.saturating_add(Weight::from_parts(102, 0).saturating_mul(x.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `BenchmarkExample::TotalSupply` (r:1 w:1)
-	/// Proof: `BenchmarkExample::TotalSupply` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Proof: `BenchmarkExample::TotalSupply` (`max_values`: None, `max_size`: Some(10071), added: 12546, mode: `MaxEncodedLen`)
 	/// Storage: `BenchmarkExample::Balance` (r:1 w:1)
-	/// Proof: `BenchmarkExample::Balance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Proof: `BenchmarkExample::Balance` (`max_values`: None, `max_size`: Some(10111), added: 12586, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[1, 10000]`.
 	fn mint(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4`
-		//  Estimated: `3469`
-		// Minimum execution time: 11_000_000 picoseconds.
-		Weight::from_parts(11_650_184, 3469)
-			// Standard Error: 10
-			.saturating_add(Weight::from_parts(5_747, 0).saturating_mul(x.into()))
+		//  Estimated: `13576`
+		// Minimum execution time: 18_802_000 picoseconds.
+		Weight::from_parts(21_546_318, 13576)
+			// Standard Error: 99
+			.saturating_add(Weight::from_parts(8_357, 0).saturating_mul(x.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `BenchmarkExample::TotalSupply` (r:10 w:10)
+	/// Proof: `BenchmarkExample::TotalSupply` (`max_values`: None, `max_size`: Some(10071), added: 12546, mode: `MaxEncodedLen`)
+	fn destroy_collection() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10710`
+		//  Estimated: `126450`
+		// Minimum execution time: 197_499_000 picoseconds.
+		Weight::from_parts(209_633_000, 126450)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
 }
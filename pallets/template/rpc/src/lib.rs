@@ -0,0 +1,171 @@
+//! RPC interface for the game marketplace pallet.
+//!
+//! Game IDs are returned as `0x`-prefixed hex and balances as decimal strings, so JavaScript
+//! clients never lose precision on large numbers.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorObject, ErrorObjectOwned},
+};
+use pallet_template_runtime_api::GameInfo;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::H256;
+use sp_runtime::traits::{Block as BlockT, MaybeDisplay};
+
+pub use pallet_template_runtime_api::GameMarketplaceApi as GameMarketplaceRuntimeApi;
+
+/// Largest page the `byOwner` and `forSale` methods return, whatever limit is asked for.
+pub const MAX_PAGE_SIZE: u32 = 1000;
+
+/// A game as returned over RPC.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcGame<AccountId> {
+	pub id: H256,
+	pub owner: AccountId,
+	pub creator: AccountId,
+	/// Creator royalty in parts per million.
+	pub royalty: u32,
+	/// Current price as a decimal string, or `None` if the game is not for sale.
+	pub price: Option<String>,
+}
+
+impl<AccountId, Balance: MaybeDisplay> From<GameInfo<AccountId, Balance>> for RpcGame<AccountId> {
+	fn from(game: GameInfo<AccountId, Balance>) -> Self {
+		Self {
+			id: H256(game.id),
+			owner: game.owner,
+			creator: game.creator,
+			royalty: game.royalty.deconstruct(),
+			price: game.price.map(|price| price.to_string()),
+		}
+	}
+}
+
+/// Number of games ever minted and still in existence.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RpcSupply {
+	pub total: u32,
+	pub live: u32,
+}
+
+#[rpc(client, server)]
+pub trait GameMarketplaceApi<BlockHash, AccountId> {
+	#[method(name = "game_byId")]
+	fn by_id(&self, id: H256, at: Option<BlockHash>) -> RpcResult<Option<RpcGame<AccountId>>>;
+
+	#[method(name = "game_byOwner")]
+	fn by_owner(
+		&self,
+		owner: AccountId,
+		offset: u32,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<RpcGame<AccountId>>>;
+
+	#[method(name = "game_forSale")]
+	fn for_sale(
+		&self,
+		offset: u32,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<RpcGame<AccountId>>>;
+
+	#[method(name = "game_supply")]
+	fn supply(&self, at: Option<BlockHash>) -> RpcResult<RpcSupply>;
+}
+
+/// Provides RPC methods to query the game marketplace.
+pub struct GameMarketplace<C, Block, Balance> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<(Block, Balance)>,
+}
+
+impl<C, Block, Balance> GameMarketplace<C, Block, Balance> {
+	/// Creates a new instance of the GameMarketplace Rpc helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+fn runtime_error(error: impl ToString) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query the game marketplace.",
+		Some(error.to_string()),
+	)
+}
+
+impl<C, Block, AccountId, Balance> GameMarketplaceApiServer<<Block as BlockT>::Hash, AccountId>
+	for GameMarketplace<C, Block, Balance>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: GameMarketplaceRuntimeApi<Block, AccountId, Balance>,
+	AccountId: Codec + Clone + Send + Sync + 'static,
+	Balance: Codec + MaybeDisplay + Send + Sync + 'static,
+{
+	fn by_id(&self, id: H256, at: Option<Block::Hash>) -> RpcResult<Option<RpcGame<AccountId>>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		let game = self.client.runtime_api().game(at_hash, id.0).map_err(runtime_error)?;
+		Ok(game.map(Into::into))
+	}
+
+	fn by_owner(
+		&self,
+		owner: AccountId,
+		offset: u32,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<RpcGame<AccountId>>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		let games = self
+			.client
+			.runtime_api()
+			.games_owned_by(at_hash, owner, offset, limit.min(MAX_PAGE_SIZE))
+			.map_err(runtime_error)?;
+		Ok(games.into_iter().map(Into::into).collect())
+	}
+
+	fn for_sale(
+		&self,
+		offset: u32,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<RpcGame<AccountId>>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		let games = self
+			.client
+			.runtime_api()
+			.listings(at_hash, offset, limit.min(MAX_PAGE_SIZE))
+			.map_err(runtime_error)?;
+		Ok(games.into_iter().map(Into::into).collect())
+	}
+
+	fn supply(&self, at: Option<Block::Hash>) -> RpcResult<RpcSupply> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		let supply = self.client.runtime_api().supply(at_hash).map_err(runtime_error)?;
+		Ok(RpcSupply { total: supply.total, live: supply.live })
+	}
+}
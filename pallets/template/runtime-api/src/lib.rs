@@ -0,0 +1,40 @@
+//! Runtime API definition for the game marketplace pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+
+pub use pallet_template::{GameInfo, Listings, Supply};
+
+sp_api::decl_runtime_apis! {
+	/// Read-only queries on the game marketplace.
+	pub trait GameMarketplaceApi<AccountId, Balance> where AccountId: Codec, Balance: Codec {
+		/// The price a buyer would pay for the game right now, or `None` if it is not for sale.
+		fn current_price(id: [u8; 32]) -> Option<Balance>;
+		/// The game with the given ID, if it exists.
+		#[api_version(2)]
+		fn game(id: [u8; 32]) -> Option<GameInfo<AccountId, Balance>>;
+		/// Up to `limit` of the games owned by `owner`, skipping the first `offset`.
+		#[api_version(2)]
+		fn games_owned_by(owner: AccountId, offset: u32, limit: u32) -> Vec<GameInfo<AccountId, Balance>>;
+		/// Up to `limit` of the games currently for sale, cheapest first, skipping the first
+		/// `offset`. Only the first `MaxListingsScanned` games in storage are considered; use
+		/// `listings_from` to page through all of them.
+		#[api_version(2)]
+		fn listings(offset: u32, limit: u32) -> Vec<GameInfo<AccountId, Balance>>;
+		/// The number of games ever minted and the number that still exist.
+		#[api_version(2)]
+		fn supply() -> Supply;
+		/// The renter while the game is rented out, otherwise its owner.
+		#[api_version(3)]
+		fn current_user(id: [u8; 32]) -> Option<AccountId>;
+		/// Up to `limit` of the games currently for sale, in storage order, starting after the
+		/// game `start`. A page reads at most `MaxListingsScanned` games, and `next` is where to
+		/// continue while there are games left to read.
+		#[api_version(4)]
+		fn listings_from(start: Option<[u8; 32]>, limit: u32) -> Listings<AccountId, Balance>;
+	}
+}
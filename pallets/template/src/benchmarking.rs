@@ -4,31 +4,977 @@ use super::*;
 
 #[allow(unused)]
 use crate::Pallet as Template;
+use alloc::{vec, vec::Vec};
+use frame::primitives::BlakeTwo256;
+use frame::traits::{fungible::Mutate, Hash, Saturating};
 use frame_benchmarking::v2::*;
 use frame_system::RawOrigin;
 
+const SEED: u32 = 0;
+
+/// Mints `count` games for `owner` and returns their ids in minting order.
+fn mint_games<T: Config>(owner: &T::AccountId, count: u32) -> Vec<[u8; 32]> {
+	(0..count)
+		.map(|i| {
+			let id: [u8; 32] = BlakeTwo256::hash_of(&(owner, i)).into();
+			Pallet::<T>::mint(owner.clone(), id, T::MaxRoyalty::get(), true)
+				.expect("every minted id is unique; qed");
+			id
+		})
+		.collect()
+}
+
+/// Mints a game from a separate creator account and hands it to `owner`, and turns on the
+/// protocol fee, so that selling it pays both a royalty and a fee.
+fn mint_resold_game<T: Config>(
+	owner: &T::AccountId,
+	index: u32,
+) -> Result<[u8; 32], BenchmarkError> {
+	let creator: T::AccountId = account("creator", index, SEED);
+	let id = mint_games::<T>(&creator, 1)[0];
+	Pallet::<T>::do_transfer(creator, owner.clone(), id)?;
+	ProtocolFee::<T>::put(Permill::from_percent(1));
+	Ok(id)
+}
+
+fn max_string<T: Config>() -> BoundedString<T> {
+	vec![b'x'; T::StringLimit::get() as usize].try_into().unwrap()
+}
+
+/// Sets the largest possible metadata on the game, paid for by `who`.
+fn set_max_metadata<T: Config>(who: &T::AccountId, id: [u8; 32]) -> DispatchResult {
+	let attributes = vec![(max_string::<T>(), max_string::<T>()); T::MaxAttributes::get() as usize];
+	Pallet::<T>::do_set_metadata(
+		who.clone(),
+		id,
+		max_string::<T>(),
+		max_string::<T>(),
+		attributes.try_into().unwrap(),
+	)
+}
+
+/// Indexes `count` placeholder games to expire at `block`.
+fn fill_listing_bucket<T: Config>(block: BlockNumberFor<T>, count: u32) {
+	let ids: Vec<[u8; 32]> =
+		(0..count).map(|i| BlakeTwo256::hash_of(&(b"listing", i)).into()).collect();
+	ListingsExpiringAt::<T>::insert(block, BoundedVec::truncate_from(ids));
+}
+
+fn fund<T: Config>(who: &T::AccountId) -> T::Balance {
+	let amount = T::ExistentialDeposit::get().saturating_mul(1_000_000u32.into());
+	pallet_balances::Pallet::<T>::set_balance(who, amount);
+	amount
+}
+
+/// Places `count` offers on the game, each from its own funded account, all expiring at
+/// `expiry`.
+fn make_offers<T: Config>(
+	id: [u8; 32],
+	count: u32,
+	expiry: BlockNumberFor<T>,
+) -> Result<Vec<T::AccountId>, BenchmarkError> {
+	(0..count)
+		.map(|i| {
+			let buyer: T::AccountId = account("offerer", i, SEED);
+			fund::<T>(&buyer);
+			Pallet::<T>::do_make_offer(buyer.clone(), id, T::MinOffer::get(), expiry)?;
+			Ok(buyer)
+		})
+		.collect()
+}
+
+/// Puts `count` freshly minted games up for auction, each from its own seller, all ending at
+/// `end`. With `bids` set every auction also gets a bid from its own funded bidder.
+fn start_auctions<T: Config>(
+	count: u32,
+	end: BlockNumberFor<T>,
+	bids: bool,
+) -> Result<Vec<[u8; 32]>, BenchmarkError> {
+	let reserve = T::ExistentialDeposit::get();
+	let mut ids = Vec::new();
+	for i in 0..count {
+		let seller: T::AccountId = account("seller", i, SEED);
+		let id = mint_resold_game::<T>(&seller, i)?;
+		Pallet::<T>::do_create_auction(seller, id, reserve, end)?;
+		if bids {
+			let bidder: T::AccountId = account("bidder", i, SEED);
+			fund::<T>(&bidder);
+			Pallet::<T>::do_bid(bidder, id, reserve)?;
+		}
+		ids.push(id);
+	}
+	Ok(ids)
+}
+
+/// Puts `count` freshly minted games up for sealed-bid auction, each from its own seller, with
+/// `bids` committed bids from funded bidders. Bidder `j` bids `ExistentialDeposit * (j + 1)`
+/// with an all-zero salt.
+fn start_sealed_auctions<T: Config>(
+	count: u32,
+	commit_end: BlockNumberFor<T>,
+	reveal_end: BlockNumberFor<T>,
+	bids: u32,
+) -> Result<Vec<[u8; 32]>, BenchmarkError> {
+	let reserve = T::ExistentialDeposit::get();
+	let bidders: Vec<T::AccountId> = (0..bids)
+		.map(|j| {
+			let bidder: T::AccountId = account("sealed_bidder", j, SEED);
+			fund::<T>(&bidder);
+			bidder
+		})
+		.collect();
+	let mut ids = Vec::new();
+	for i in 0..count {
+		let seller: T::AccountId = account("seller", i, SEED);
+		let id = mint_resold_game::<T>(&seller, i)?;
+		Pallet::<T>::do_create_sealed_auction(seller, id, reserve, commit_end, reveal_end)?;
+		for (j, bidder) in bidders.iter().enumerate() {
+			let commitment =
+				Pallet::<T>::sealed_bid_commitment(bidder, sealed_amount::<T>(j as u32), [0; 32]);
+			Pallet::<T>::do_commit_bid(bidder.clone(), id, commitment, sealed_amount::<T>(bids))?;
+		}
+		ids.push(id);
+	}
+	Ok(ids)
+}
+
+fn sealed_amount<T: Config>(index: u32) -> T::Balance {
+	T::ExistentialDeposit::get().saturating_mul((index + 1).into())
+}
+
+/// Mints `count` games for `owner`, with IDs that depend on `seed` and so do not clash with those
+/// of `mint_games`.
+fn mint_swap_games<T: Config>(owner: &T::AccountId, seed: u32, count: u32) -> Vec<[u8; 32]> {
+	(0..count)
+		.map(|i| {
+			let id: [u8; 32] = BlakeTwo256::hash_of(&(b"swap", owner, seed, i)).into();
+			Pallet::<T>::mint(owner.clone(), id, T::MaxRoyalty::get(), true)
+				.expect("every minted id is unique; qed");
+			id
+		})
+		.collect()
+}
+
+/// Proposes a swap of `MaxSwapGames` freshly minted games on each side. With `offered` set that
+/// game takes the place of one of the proposer's.
+fn propose_full_swap<T: Config>(
+	proposer: &T::AccountId,
+	counterparty: &T::AccountId,
+	seed: u32,
+	offered: Option<[u8; 32]>,
+	expiry: BlockNumberFor<T>,
+) -> Result<u32, BenchmarkError> {
+	let max = T::MaxSwapGames::get();
+	let mut give = mint_swap_games::<T>(proposer, seed, max - offered.iter().count() as u32);
+	give.extend(offered);
+	let want = mint_swap_games::<T>(counterparty, seed, max);
+	fund::<T>(proposer);
+	let swap_id = NextSwapId::<T>::get();
+	Pallet::<T>::do_propose_swap(
+		proposer.clone(),
+		counterparty.clone(),
+		give.try_into().unwrap(),
+		want.try_into().unwrap(),
+		None,
+		expiry,
+	)?;
+	Ok(swap_id)
+}
+
+/// Has `owner` offer their game in `MaxSwapsPerGame` full swaps, so that moving it cancels as
+/// many swaps, over as many games, as possible. `seed` must differ between calls.
+fn propose_swaps<T: Config>(
+	id: [u8; 32],
+	owner: &T::AccountId,
+	seed: u32,
+) -> Result<(), BenchmarkError> {
+	let count = T::MaxSwapsPerGame::get();
+	for i in 0..count {
+		let index = seed * count + i;
+		let counterparty: T::AccountId = account("swapper", index, SEED);
+		propose_full_swap::<T>(owner, &counterparty, index, Some(id), (1_000 + seed).into())?;
+	}
+	Ok(())
+}
+
+/// Indexes `count` placeholder swaps to expire at `block`.
+fn fill_swap_bucket<T: Config>(block: BlockNumberFor<T>, count: u32) {
+	SwapsExpiringAt::<T>::insert(block, BoundedVec::truncate_from(vec![u32::MAX; count as usize]));
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
 
 	#[benchmark]
-	fn do_something() {
-		let value = 100u32;
+	fn create_game() {
 		let caller: T::AccountId = whitelisted_caller();
+
 		#[extrinsic_call]
-		do_something(RawOrigin::Signed(caller), value);
+		create_game(RawOrigin::Signed(caller.clone()), T::MaxRoyalty::get());
 
-		assert_eq!(Something::<T>::get(), Some(value));
+		assert_eq!(GamesOwnedBy::<T>::iter_key_prefix(&caller).count(), 1);
 	}
 
 	#[benchmark]
-	fn cause_error() {
-		Something::<T>::put(100u32);
+	fn transfer_game() -> Result<(), BenchmarkError> {
 		let caller: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+		let id = mint_games::<T>(&caller, 1)[0];
+		// The listing and its expiry are cleared, every open offer is released and every swap
+		// cancelled when the game changes hands.
+		let price = T::ExistentialDeposit::get();
+		Pallet::<T>::do_set_price(caller.clone(), id, Some(price), Some(10u32.into()))?;
+		make_offers::<T>(id, T::MaxOffersPerGame::get(), 10u32.into())?;
+		propose_swaps::<T>(id, &caller, 0)?;
+
+		#[extrinsic_call]
+		transfer_game(RawOrigin::Signed(caller), recipient.clone(), id);
+
+		assert_eq!(Games::<T>::get(id).unwrap().owner, recipient);
+		assert!(!ListingExpiry::<T>::contains_key(id));
+		assert!(!Offers::<T>::contains_key(id));
+		assert!(!SwapsByGame::<T>::contains_key(id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_game_price() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let id = mint_games::<T>(&caller, 1)[0];
+		let price = T::ExistentialDeposit::get();
+		let (old_expiry, new_expiry): (BlockNumberFor<T>, BlockNumberFor<T>) =
+			(10u32.into(), 20u32.into());
+		// Relisting moves the game between two nearly full expiry buckets.
+		fill_listing_bucket::<T>(old_expiry, T::MaxListingsPerBlock::get() - 1);
+		fill_listing_bucket::<T>(new_expiry, T::MaxListingsPerBlock::get() - 1);
+		Pallet::<T>::do_set_price(caller.clone(), id, Some(price), Some(old_expiry))?;
+
+		#[extrinsic_call]
+		set_game_price(RawOrigin::Signed(caller), id, Some(price), Some(new_expiry));
+
+		assert_eq!(ListingExpiry::<T>::get(id), Some(new_expiry));
+		assert!(!ListingsExpiringAt::<T>::get(old_expiry).contains(&id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn buy_game() -> Result<(), BenchmarkError> {
+		let seller: T::AccountId = account("seller", 0, SEED);
+		let buyer: T::AccountId = whitelisted_caller();
+		let id = mint_resold_game::<T>(&seller, 0)?;
+		fund::<T>(&seller);
+		let price = fund::<T>(&buyer) / 2u32.into();
+		// Buying from a Dutch auction also prices the game and removes the auction.
+		Pallet::<T>::do_create_dutch_auction(seller.clone(), id, price, price, 1u32.into())?;
+		make_offers::<T>(id, T::MaxOffersPerGame::get(), 10u32.into())?;
+		propose_swaps::<T>(id, &seller, 0)?;
+
+		#[extrinsic_call]
+		buy_game(RawOrigin::Signed(buyer.clone()), id, price);
+
+		assert_eq!(Games::<T>::get(id).unwrap().owner, buyer);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn create_auction() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let id = mint_games::<T>(&caller, 1)[0];
+		let end: BlockNumberFor<T> = 10u32.into();
+		// Leave a single free slot so the ending-block vector is at its largest.
+		start_auctions::<T>(T::MaxAuctionsPerBlock::get() - 1, end, false)?;
+		let reserve = T::ExistentialDeposit::get();
+
+		#[extrinsic_call]
+		create_auction(RawOrigin::Signed(caller), id, reserve, end);
+
+		assert!(Auctions::<T>::contains_key(id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn bid() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		// Outbidding an existing bid releases the previous hold as well.
+		let id = start_auctions::<T>(1, 10u32.into(), true)?[0];
+		let amount = fund::<T>(&caller) / 2u32.into();
+
+		#[extrinsic_call]
+		bid(RawOrigin::Signed(caller.clone()), id, amount);
+
+		assert_eq!(Auctions::<T>::get(id).unwrap().highest_bid, Some((caller, amount)));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_auction() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let id = mint_games::<T>(&caller, 1)[0];
+		let end: BlockNumberFor<T> = 10u32.into();
+		start_auctions::<T>(T::MaxAuctionsPerBlock::get() - 1, end, false)?;
+		Pallet::<T>::do_create_auction(caller.clone(), id, T::ExistentialDeposit::get(), end)?;
+
+		#[extrinsic_call]
+		cancel_auction(RawOrigin::Signed(caller), id);
+
+		assert!(!Auctions::<T>::contains_key(id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn settle_auction() -> Result<(), BenchmarkError> {
+		let end: BlockNumberFor<T> = 10u32.into();
+		// Settle the first auction out of a full ending-block vector.
+		let ids = start_auctions::<T>(T::MaxAuctionsPerBlock::get(), end, true)?;
+		make_offers::<T>(ids[0], T::MaxOffersPerGame::get(), 100u32.into())?;
+		let seller: T::AccountId = account("seller", 0, SEED);
+		propose_swaps::<T>(ids[0], &seller, 0)?;
+
+		#[block]
+		{
+			assert!(Pallet::<T>::settle_next_auction(end));
+		}
+
+		let bidder: T::AccountId = account("bidder", 0, SEED);
+		assert_eq!(Games::<T>::get(ids[0]).unwrap().owner, bidder);
+		assert_eq!(AuctionsEndingAt::<T>::get(end).len() as u32, T::MaxAuctionsPerBlock::get() - 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn create_dutch_auction() {
+		let caller: T::AccountId = whitelisted_caller();
+		let id = mint_games::<T>(&caller, 1)[0];
+		let floor_price = T::ExistentialDeposit::get();
+		let start_price = floor_price.saturating_mul(10u32.into());
+
+		#[extrinsic_call]
+		create_dutch_auction(RawOrigin::Signed(caller), id, start_price, floor_price, 10u32.into());
+
+		assert!(DutchAuctions::<T>::contains_key(id));
+	}
+
+	#[benchmark]
+	fn make_offer() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let id = mint_games::<T>(&owner, 1)[0];
+		let expiry: BlockNumberFor<T> = 10u32.into();
+		// The game is full, so the new offer also releases the lowest one.
+		let outbid = make_offers::<T>(id, T::MaxOffersPerGame::get(), expiry)?;
+		let amount = fund::<T>(&caller) / 2u32.into();
+
+		#[extrinsic_call]
+		make_offer(RawOrigin::Signed(caller.clone()), id, amount, expiry);
+
+		assert_eq!(Offers::<T>::get(id).len() as u32, T::MaxOffersPerGame::get());
+		assert!(!Offers::<T>::get(id).iter().any(|offer| offer.buyer == outbid[0]));
+		assert!(Offers::<T>::get(id).iter().any(|offer| offer.buyer == caller));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn withdraw_offer() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let id = mint_games::<T>(&owner, 1)[0];
+		// The withdrawn offer is the last one, so finding it scans every entry.
+		let buyer = make_offers::<T>(id, T::MaxOffersPerGame::get(), 10u32.into())?.pop().unwrap();
+
+		#[extrinsic_call]
+		withdraw_offer(RawOrigin::Signed(buyer), id);
+
+		assert_eq!(Offers::<T>::get(id).len() as u32, T::MaxOffersPerGame::get() - 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn accept_offer() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let id = mint_resold_game::<T>(&caller, 0)?;
+		let buyers = make_offers::<T>(id, T::MaxOffersPerGame::get(), 10u32.into())?;
+		let buyer = buyers.last().unwrap().clone();
+		propose_swaps::<T>(id, &caller, 0)?;
+
+		#[extrinsic_call]
+		accept_offer(RawOrigin::Signed(caller), id, buyer.clone());
+
+		assert_eq!(Games::<T>::get(id).unwrap().owner, buyer);
+		assert!(!Offers::<T>::contains_key(id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn expire_offers(n: Linear<0, { T::MaxOffersPerBlock::get() }>) -> Result<(), BenchmarkError> {
+		let expiry: BlockNumberFor<T> = 10u32.into();
+		for i in 0..n {
+			let owner: T::AccountId = account("owner", i, SEED);
+			let buyer: T::AccountId = account("buyer", i, SEED);
+			let id = mint_games::<T>(&owner, 1)[0];
+			fund::<T>(&buyer);
+			Pallet::<T>::do_make_offer(buyer, id, T::MinOffer::get(), expiry)?;
+		}
+
+		#[block]
+		{
+			Pallet::<T>::expire_offers(expiry);
+		}
+
+		assert!(!OffersExpiringAt::<T>::contains_key(expiry));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn expire_listings(
+		n: Linear<0, { T::MaxListingsPerBlock::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let expiry: BlockNumberFor<T> = 10u32.into();
+		let ids = mint_games::<T>(&owner, n);
+		for id in ids.iter() {
+			Pallet::<T>::do_set_price(
+				owner.clone(),
+				*id,
+				Some(T::ExistentialDeposit::get()),
+				Some(expiry),
+			)?;
+		}
+
+		#[block]
+		{
+			Pallet::<T>::expire_listings(expiry);
+		}
+
+		assert!(!ListingsExpiringAt::<T>::contains_key(expiry));
+		assert!(ids.iter().all(|id| Games::<T>::get(id).unwrap().value.is_none()));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_protocol_fee() {
+		let fee = Permill::from_percent(5);
+
+		#[extrinsic_call]
+		set_protocol_fee(RawOrigin::Root, fee);
+
+		assert_eq!(ProtocolFee::<T>::get(), fee);
+	}
+
+	#[benchmark]
+	fn set_metadata() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		// Replacing metadata paid for by the creator refunds them and charges the caller.
+		let id = mint_resold_game::<T>(&caller, 0)?;
+		let creator = Games::<T>::get(id).unwrap().creator;
+		fund::<T>(&creator);
+		fund::<T>(&caller);
+		set_max_metadata::<T>(&creator, id)?;
+		let attributes =
+			vec![(max_string::<T>(), max_string::<T>()); T::MaxAttributes::get() as usize];
+
+		#[extrinsic_call]
+		set_metadata(
+			RawOrigin::Signed(caller.clone()),
+			id,
+			max_string::<T>(),
+			max_string::<T>(),
+			attributes.try_into().unwrap(),
+		);
+
+		assert_eq!(GameMetadata::<T>::get(id).unwrap().depositor, caller);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn clear_metadata() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let id = mint_games::<T>(&caller, 1)[0];
+		fund::<T>(&caller);
+		set_max_metadata::<T>(&caller, id)?;
+
+		#[extrinsic_call]
+		clear_metadata(RawOrigin::Signed(caller), id);
+
+		assert!(!GameMetadata::<T>::contains_key(id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn lock_metadata() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let id = mint_games::<T>(&caller, 1)[0];
+		fund::<T>(&caller);
+		set_max_metadata::<T>(&caller, id)?;
+
+		#[extrinsic_call]
+		lock_metadata(RawOrigin::Signed(caller), id);
+
+		assert!(GameMetadata::<T>::get(id).unwrap().locked);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn approve() {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let caller: T::AccountId = whitelisted_caller();
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let id = mint_games::<T>(&owner, 1)[0];
+		Operators::<T>::insert(&owner, &caller, ());
+
+		#[extrinsic_call]
+		approve(RawOrigin::Signed(caller), id, Some(delegate.clone()));
+
+		assert_eq!(Approvals::<T>::get(id), Some(delegate));
+	}
+
+	#[benchmark]
+	fn set_operator() {
+		let caller: T::AccountId = whitelisted_caller();
+		let operator: T::AccountId = account("operator", 0, SEED);
+
+		#[extrinsic_call]
+		set_operator(RawOrigin::Signed(caller.clone()), operator.clone(), true);
+
+		assert!(Operators::<T>::contains_key(&caller, &operator));
+	}
+
+	#[benchmark]
+	fn transfer_from() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let caller: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+		let id = mint_games::<T>(&owner, 1)[0];
+		let price = T::ExistentialDeposit::get();
+		Pallet::<T>::do_set_price(owner.clone(), id, Some(price), Some(10u32.into()))?;
+		make_offers::<T>(id, T::MaxOffersPerGame::get(), 10u32.into())?;
+		propose_swaps::<T>(id, &owner, 0)?;
+		// The game is approved to someone else, so the caller is only let through as an operator.
+		Approvals::<T>::insert(id, &recipient);
+		Operators::<T>::insert(&owner, &caller, ());
+
+		#[extrinsic_call]
+		transfer_from(RawOrigin::Signed(caller), owner, recipient.clone(), id);
+
+		assert_eq!(Games::<T>::get(id).unwrap().owner, recipient);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn burn_game() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let caller: T::AccountId = whitelisted_caller();
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let id = mint_games::<T>(&owner, 1)[0];
+		fund::<T>(&owner);
+		set_max_metadata::<T>(&owner, id)?;
+		make_offers::<T>(id, T::MaxOffersPerGame::get(), 10u32.into())?;
+		propose_swaps::<T>(id, &owner, 0)?;
+		Approvals::<T>::insert(id, &delegate);
+		Operators::<T>::insert(&owner, &caller, ());
+
+		#[extrinsic_call]
+		burn_game(RawOrigin::Signed(caller), id);
+
+		assert!(!Games::<T>::contains_key(id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn lend_game() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let renter: T::AccountId = account("renter", 0, SEED);
+		let id = mint_games::<T>(&caller, 1)[0];
+		let until: BlockNumberFor<T> = 100u32.into();
+		// A free rental starts at once and takes the game off sale.
+		let expiry: BlockNumberFor<T> = 10u32.into();
+		fill_listing_bucket::<T>(expiry, T::MaxListingsPerBlock::get() - 1);
+		Pallet::<T>::do_set_price(
+			caller.clone(),
+			id,
+			Some(T::ExistentialDeposit::get()),
+			Some(expiry),
+		)?;
+
+		#[extrinsic_call]
+		lend_game(RawOrigin::Signed(caller), id, renter.clone(), until, None);
+
+		assert_eq!(Pallet::<T>::current_user(id), Some(renter));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn accept_rental() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let renter: T::AccountId = whitelisted_caller();
+		let id = mint_games::<T>(&owner, 1)[0];
+		let fee = fund::<T>(&renter) / 2u32.into();
+		let until: BlockNumberFor<T> = 100u32.into();
+		let expiry: BlockNumberFor<T> = 10u32.into();
+		fill_listing_bucket::<T>(expiry, T::MaxListingsPerBlock::get() - 1);
+		Pallet::<T>::do_set_price(
+			owner.clone(),
+			id,
+			Some(T::ExistentialDeposit::get()),
+			Some(expiry),
+		)?;
+		Pallet::<T>::do_lend(owner, id, renter.clone(), until, Some(fee))?;
+
+		#[extrinsic_call]
+		accept_rental(RawOrigin::Signed(renter.clone()), id);
+
+		assert_eq!(Pallet::<T>::current_user(id), Some(renter));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn create_games(n: Linear<1, { T::MaxBatchSize::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		create_games(RawOrigin::Signed(caller.clone()), n, T::MaxRoyalty::get());
+
+		assert_eq!(GamesOwnedBy::<T>::iter_key_prefix(&caller).count(), n as usize);
+	}
+
+	#[benchmark]
+	fn transfer_games(n: Linear<1, { T::MaxBatchSize::get() }>) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+		let ids = mint_games::<T>(&caller, n);
+		// Every game clears a listing, releases a full set of offers and cancels a full set of
+		// swaps, spread over several blocks to stay within the per-block expiry limits.
+		let price = T::ExistentialDeposit::get();
+		for (i, id) in ids.iter().enumerate() {
+			let expiry: BlockNumberFor<T> = (10 + i as u32).into();
+			Pallet::<T>::do_set_price(caller.clone(), *id, Some(price), Some(expiry))?;
+			make_offers::<T>(*id, T::MaxOffersPerGame::get(), expiry)?;
+			propose_swaps::<T>(*id, &caller, i as u32)?;
+		}
+		let transfers: Vec<_> = ids.iter().map(|id| (recipient.clone(), *id)).collect();
+
+		#[extrinsic_call]
+		transfer_games(RawOrigin::Signed(caller), transfers.try_into().unwrap());
+
+		assert_eq!(GamesOwnedBy::<T>::iter_key_prefix(&recipient).count(), n as usize);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn create_sealed_auction() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let id = mint_games::<T>(&caller, 1)[0];
+		let reveal_end: BlockNumberFor<T> = 10u32.into();
+		// Leave a single free slot so the ending-block vector is at its largest.
+		start_sealed_auctions::<T>(T::MaxAuctionsPerBlock::get() - 1, 5u32.into(), reveal_end, 0)?;
+		let reserve = T::ExistentialDeposit::get();
+
+		#[extrinsic_call]
+		create_sealed_auction(RawOrigin::Signed(caller), id, reserve, 5u32.into(), reveal_end);
+
+		assert!(SealedAuctions::<T>::contains_key(id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn commit_bid() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let bids = T::MaxSealedBids::get() - 1;
+		let id = start_sealed_auctions::<T>(1, 5u32.into(), 10u32.into(), bids)?[0];
+		fund::<T>(&caller);
+		let commitment =
+			Pallet::<T>::sealed_bid_commitment(&caller, sealed_amount::<T>(bids), [0; 32]);
+
+		#[extrinsic_call]
+		commit_bid(RawOrigin::Signed(caller), id, commitment, sealed_amount::<T>(bids));
+
+		assert_eq!(SealedBids::<T>::get(id).len() as u32, T::MaxSealedBids::get());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reveal_bid() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let commit_end: BlockNumberFor<T> = 5u32.into();
+		let bids = T::MaxSealedBids::get() - 1;
+		let id = start_sealed_auctions::<T>(1, commit_end, 10u32.into(), bids)?[0];
+		// The caller's bid is the last one and outbids an already revealed bid, whose deposit
+		// is released.
+		fund::<T>(&caller);
+		let amount = sealed_amount::<T>(bids);
+		let commitment = Pallet::<T>::sealed_bid_commitment(&caller, amount, [0; 32]);
+		Pallet::<T>::do_commit_bid(caller.clone(), id, commitment, amount)?;
+		frame_system::Pallet::<T>::set_block_number(commit_end);
+		let first: T::AccountId = account("sealed_bidder", 0, SEED);
+		Pallet::<T>::do_reveal_bid(first, id, sealed_amount::<T>(0), [0; 32])?;
+
+		#[extrinsic_call]
+		reveal_bid(RawOrigin::Signed(caller.clone()), id, amount, [0; 32]);
+
+		assert_eq!(SealedAuctions::<T>::get(id).unwrap().highest_bid, Some((caller, amount)));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn settle_sealed_auction() -> Result<(), BenchmarkError> {
+		let commit_end: BlockNumberFor<T> = 5u32.into();
+		let reveal_end: BlockNumberFor<T> = 10u32.into();
+		// Settle the first auction out of a full ending-block vector.
+		let ids = start_sealed_auctions::<T>(
+			T::MaxAuctionsPerBlock::get(),
+			commit_end,
+			reveal_end,
+			T::MaxSealedBids::get(),
+		)?;
+		make_offers::<T>(ids[0], T::MaxOffersPerGame::get(), 100u32.into())?;
+		let seller: T::AccountId = account("seller", 0, SEED);
+		propose_swaps::<T>(ids[0], &seller, 0)?;
+		// Only the first bid is revealed; every other deposit goes through the unrevealed-bid
+		// policy.
+		frame_system::Pallet::<T>::set_block_number(commit_end);
+		let winner: T::AccountId = account("sealed_bidder", 0, SEED);
+		Pallet::<T>::do_reveal_bid(winner.clone(), ids[0], sealed_amount::<T>(0), [0; 32])?;
+
+		#[block]
+		{
+			assert!(Pallet::<T>::settle_next_sealed_auction(reveal_end));
+		}
+
+		assert_eq!(Games::<T>::get(ids[0]).unwrap().owner, winner);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn propose_swap(n: Linear<1, { T::MaxSwapGames::get() * 2 }>) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let counterparty: T::AccountId = account("counterparty", 0, SEED);
+		let max = T::MaxSwapGames::get();
+		let give = mint_swap_games::<T>(&caller, 0, n.min(max));
+		let want = mint_swap_games::<T>(&counterparty, 0, n.saturating_sub(max));
+		// Leave a single free slot in every index the proposal is added to.
+		for id in give.iter() {
+			let placeholders = vec![u32::MAX; T::MaxSwapsPerGame::get() as usize - 1];
+			SwapsByGame::<T>::insert(id, BoundedVec::truncate_from(placeholders));
+		}
+		let expiry: BlockNumberFor<T> = 10u32.into();
+		fill_swap_bucket::<T>(expiry, T::MaxSwapsPerBlock::get() - 1);
+		let top_up = Some(TopUp::Give(T::ExistentialDeposit::get()));
+
+		#[extrinsic_call]
+		propose_swap(
+			RawOrigin::Signed(caller),
+			counterparty,
+			give.try_into().unwrap(),
+			want.try_into().unwrap(),
+			top_up,
+			expiry,
+		);
+
+		assert!(Swaps::<T>::contains_key(0));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn accept_swap() -> Result<(), BenchmarkError> {
+		let proposer: T::AccountId = account("proposer", 0, SEED);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&proposer);
+		fund::<T>(&caller);
+		let swap_id = propose_full_swap::<T>(&proposer, &caller, 0, None, 10u32.into())?;
+		let swap = Swaps::<T>::get(swap_id).unwrap();
+		Swaps::<T>::mutate(swap_id, |swap| {
+			swap.as_mut().unwrap().top_up = Some(TopUp::Give(T::ExistentialDeposit::get()));
+		});
+		// Every game that moves releases a full set of offers and cancels every other swap it is
+		// part of.
+		let games: Vec<_> = swap
+			.give
+			.iter()
+			.map(|id| (*id, &proposer))
+			.chain(swap.want.iter().map(|id| (*id, &caller)))
+			.collect();
+		let others = T::MaxSwapsPerGame::get() - 1;
+		for (i, (id, owner)) in games.into_iter().enumerate() {
+			make_offers::<T>(id, T::MaxOffersPerGame::get(), (100 + i as u32).into())?;
+			for j in 0..others {
+				let seed = 1 + i as u32 * others + j;
+				let swapper: T::AccountId = account("swapper", seed, SEED);
+				propose_full_swap::<T>(owner, &swapper, seed, Some(id), (1_000 + seed).into())?;
+			}
+		}
+
+		#[extrinsic_call]
+		accept_swap(RawOrigin::Signed(caller.clone()), swap_id);
+
+		assert!(swap.give.iter().all(|id| Games::<T>::get(id).unwrap().owner == caller));
+		assert!(Swaps::<T>::iter().next().is_none());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_swap() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let counterparty: T::AccountId = account("counterparty", 0, SEED);
+		let expiry: BlockNumberFor<T> = 10u32.into();
+		fill_swap_bucket::<T>(expiry, T::MaxSwapsPerBlock::get() - 1);
+		let swap_id = propose_full_swap::<T>(&caller, &counterparty, 0, None, expiry)?;
+
+		#[extrinsic_call]
+		cancel_swap(RawOrigin::Signed(caller), swap_id);
+
+		assert!(!Swaps::<T>::contains_key(swap_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn expire_swaps(n: Linear<0, { T::MaxSwapsPerBlock::get() }>) -> Result<(), BenchmarkError> {
+		let expiry: BlockNumberFor<T> = 10u32.into();
+		let counterparty: T::AccountId = account("counterparty", 0, SEED);
+		for i in 0..n {
+			let proposer: T::AccountId = account("proposer", i, SEED);
+			propose_full_swap::<T>(&proposer, &counterparty, i, None, expiry)?;
+		}
+
+		#[block]
+		{
+			Pallet::<T>::expire_swaps(expiry);
+		}
+
+		assert!(Swaps::<T>::iter().next().is_none());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn create_bundle(n: Linear<1, { T::MaxBundleSize::get() }>) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let games = mint_games::<T>(&caller, n);
+		// Every game is listed with an expiry, so bundling it also clears the listing.
+		for id in games.iter() {
+			let price = Some(T::ExistentialDeposit::get());
+			Pallet::<T>::do_set_price(caller.clone(), *id, price, Some(10u32.into()))?;
+		}
+		let price = T::ExistentialDeposit::get();
+
+		#[extrinsic_call]
+		create_bundle(RawOrigin::Signed(caller), games.clone().try_into().unwrap(), price);
+
+		assert!(games.iter().all(BundledGames::<T>::contains_key));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_bundle() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let games = mint_games::<T>(&caller, T::MaxBundleSize::get());
+		let price = T::ExistentialDeposit::get();
+		Pallet::<T>::do_create_bundle(caller.clone(), games.try_into().unwrap(), price)?;
+
+		#[extrinsic_call]
+		cancel_bundle(RawOrigin::Signed(caller), 0);
+
+		assert!(!Bundles::<T>::contains_key(0));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn buy_bundle() -> Result<(), BenchmarkError> {
+		let seller: T::AccountId = account("seller", 0, SEED);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&seller);
+		let price = fund::<T>(&caller) / 2u32.into();
+		// Every game pays its own creator a royalty, releases a full set of offers and cancels a
+		// full set of swaps.
+		let mut games = Vec::new();
+		for i in 0..T::MaxBundleSize::get() {
+			let id = mint_resold_game::<T>(&seller, i)?;
+			make_offers::<T>(id, T::MaxOffersPerGame::get(), (100 + i).into())?;
+			propose_swaps::<T>(id, &seller, i)?;
+			games.push(id);
+		}
+		Pallet::<T>::do_create_bundle(seller, games.clone().try_into().unwrap(), price)?;
+
+		#[extrinsic_call]
+		buy_bundle(RawOrigin::Signed(caller.clone()), 0, price);
+
+		assert!(games.iter().all(|id| Games::<T>::get(id).unwrap().owner == caller));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn fill_order() -> Result<(), BenchmarkError> {
+		let (public, seller) = T::BenchmarkHelper::signer();
+		let buyer: T::AccountId = whitelisted_caller();
+		let id = mint_resold_game::<T>(&seller, 0)?;
+		fund::<T>(&seller);
+		let price = fund::<T>(&buyer) / 2u32.into();
+		// The sale also clears a listing with an expiry, a full set of offers and of swaps.
+		Pallet::<T>::do_set_price(seller.clone(), id, Some(price), Some(10u32.into()))?;
+		make_offers::<T>(id, T::MaxOffersPerGame::get(), 10u32.into())?;
+		propose_swaps::<T>(id, &seller, 0)?;
+		let order = Order { id, price, expiry: 10u32.into(), nonce: 0 };
+		let signature = T::BenchmarkHelper::sign(&public, &Pallet::<T>::order_payload(&order));
+
+		#[extrinsic_call]
+		fill_order(RawOrigin::Signed(buyer.clone()), order, signature);
+
+		assert_eq!(Games::<T>::get(id).unwrap().owner, buyer);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_order() {
+		let caller: T::AccountId = whitelisted_caller();
+		let id = mint_games::<T>(&caller, 1)[0];
+		fund::<T>(&caller);
+		let order = Order { id, price: 1u32.into(), expiry: 10u32.into(), nonce: 0 };
+		let order_hash = Pallet::<T>::order_hash(&order);
+
+		#[extrinsic_call]
+		cancel_order(RawOrigin::Signed(caller.clone()), order);
+
+		assert!(ClosedOrders::<T>::contains_key(&caller, order_hash));
+	}
+
+	#[benchmark]
+	fn bump_order_nonce() {
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		bump_order_nonce(RawOrigin::Signed(caller.clone()));
+
+		assert_eq!(OrderNonces::<T>::get(&caller), 1);
+	}
+
+	#[benchmark]
+	fn expire_closed_order() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let id = mint_games::<T>(&caller, 1)[0];
+		fund::<T>(&caller);
+		let expiry: BlockNumberFor<T> = 10u32.into();
+		let order = Order { id, price: 1u32.into(), expiry, nonce: 0 };
+		Pallet::<T>::do_cancel_order(caller.clone(), order)?;
+
+		#[block]
+		{
+			assert!(Pallet::<T>::expire_closed_order(expiry));
+		}
+
+		assert!(ClosedOrders::<T>::iter().next().is_none());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn issue_soulbound_game() -> Result<(), BenchmarkError> {
+		let origin =
+			T::IssuerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let owner: T::AccountId = account("owner", 0, SEED);
+
 		#[extrinsic_call]
-		cause_error(RawOrigin::Signed(caller));
+		_(origin as T::RuntimeOrigin, owner.clone());
 
-		assert_eq!(Something::<T>::get(), Some(101u32));
+		let id = GamesOwnedBy::<T>::iter_key_prefix(&owner).next().unwrap();
+		assert!(!Games::<T>::get(id).unwrap().transferable);
+		Ok(())
 	}
 
 	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
@@ -1,86 +1,1699 @@
 use super::*;
+use alloc::vec::Vec;
+use frame::arithmetic::{Perbill, Saturating, Zero};
 use frame::primitives::BlakeTwo256;
-use frame::traits::{Currency, ExistenceRequirement::KeepAlive, Hash};
+use frame::traits::{
+	fungible::{Inspect, Mutate, MutateHold},
+	tokens::{DepositConsequence, Fortitude, Precision, Preservation, Provenance, Restriction},
+	Hash,
+};
+#[cfg(any(feature = "try-runtime", test))]
+use frame_support::sp_runtime::TryRuntimeError;
+use frame_support::storage::with_storage_layer;
 
 impl<T: Config> Pallet<T> {
 	pub fn gen_game_key() -> [u8; 32] {
-		let unique_payload = (
-			frame_system::Pallet::<T>::parent_hash(),
-			frame_system::Pallet::<T>::block_number(),
-			frame_system::Pallet::<T>::extrinsic_index(),
-			GameCount::<T>::get(),
-		);
-		let hash: [u8; 32] = BlakeTwo256::hash_of(&unique_payload).into();
+		// The mint count keeps IDs unique when the randomness does not change within a block.
+		let count = GameCount::<T>::get();
+		let (seed, _) = T::Randomness::random(&(b"game_id", count).encode());
+		let hash: [u8; 32] = BlakeTwo256::hash_of(&(seed, count)).into();
 		hash
 	}
 
+	/// Moves the game to `to`, taking it off sale and dropping its approval, offers and swaps.
 	pub fn do_transfer(from: T::AccountId, to: T::AccountId, id: [u8; 32]) -> DispatchResult {
 		ensure!(!from.eq(&to), Error::<T>::TransferToSelf);
+		Self::ensure_unlocked(&id)?;
 
 		let mut game = Games::<T>::get(id).ok_or(Error::<T>::GameNotFound)?;
 
 		ensure!(game.owner.eq(&from), Error::<T>::NotAuthorized);
-
-		let mut to_owned = GamesOwnedBy::<T>::get(&to);
-		to_owned.try_push(id).map_err(|_| Error::<T>::TooManyGamesOwned)?;
-
-		let mut from_owned = GamesOwnedBy::<T>::get(&from);
-		let index = from_owned.iter().position(|&x| x == id).ok_or(Error::<T>::NotAuthorized)?;
-		from_owned.remove(index);
+		Self::ensure_transferable(&game)?;
+		Self::ensure_not_rented(&game)?;
 
 		game.owner = to.clone();
+		game.value = None;
+		game.rental = None;
 		Games::<T>::insert(id, game);
-		GamesOwnedBy::<T>::insert(&to, to_owned);
-		GamesOwnedBy::<T>::insert(&from, from_owned);
+		Self::clear_listing_expiry(id);
+		GamesOwnedBy::<T>::remove(&from, id);
+		GamesOwnedBy::<T>::insert(&to, id, ());
+		Approvals::<T>::remove(id);
+		RentalOffers::<T>::remove(id);
+		Self::clear_offers(id);
+		Self::cancel_swaps(id);
+		Self::refund_metadata_deposit(&from, id);
 
 		Self::deposit_event(Event::<T>::GameTransferred { from, to, id });
 		Ok(())
 	}
 
-	pub fn mint(owner: T::AccountId, id: [u8; 32]) -> DispatchResult {
-		let game = Game { key_data: id, owner: owner.clone(), value: None };
+	pub fn mint(
+		owner: T::AccountId,
+		id: [u8; 32],
+		royalty: Permill,
+		transferable: bool,
+	) -> DispatchResult {
+		ensure!(royalty <= T::MaxRoyalty::get(), Error::<T>::RoyaltyTooHigh);
+		let game = Game {
+			key_data: id,
+			owner: owner.clone(),
+			creator: owner.clone(),
+			value: None,
+			royalty,
+			rental: None,
+			transferable,
+		};
 
 		ensure!(!Games::<T>::contains_key(id), Error::<T>::DuplicatedGame);
 
 		let current_count = GameCount::<T>::get();
 		let updated_count = current_count.checked_add(1).ok_or(Error::<T>::TooManyGames)?;
 		GameCount::<T>::set(updated_count);
+		LiveGameCount::<T>::mutate(|count| count.saturating_inc());
 		Games::<T>::insert(id, game);
-		GamesOwnedBy::<T>::try_mutate(&owner, |games| {
-			games.try_push(id).map_err(|_| Error::<T>::TooManyGamesOwned)
-		})?;
+		GamesOwnedBy::<T>::insert(&owner, id, ());
 
 		Self::deposit_event(Event::<T>::GameCreated { owner, id });
 		Ok(())
 	}
 
+	/// Mints a game listed in the genesis config, panicking if the entry is invalid.
+	pub(crate) fn build_genesis_game(
+		owner: T::AccountId,
+		id: [u8; 32],
+		price: Option<T::Balance>,
+		metadata: Option<GenesisMetadata>,
+	) {
+		Self::mint(owner.clone(), id, Permill::zero(), true)
+			.expect("genesis games must have unique IDs and fit within the game count");
+		if price.is_some() {
+			Self::do_set_price(owner.clone(), id, price, None)
+				.expect("a freshly minted genesis game can be listed");
+		}
+		if let Some((name, uri, attributes)) = metadata {
+			let bounded = |s: Vec<u8>| -> BoundedString<T> {
+				s.try_into().expect("genesis metadata strings must fit within StringLimit")
+			};
+			let attributes: Vec<_> = attributes
+				.into_iter()
+				.map(|(key, value)| (bounded(key), bounded(value)))
+				.collect();
+			let attributes = attributes
+				.try_into()
+				.expect("genesis metadata must have at most MaxAttributes attributes");
+			Self::do_set_metadata(owner, id, bounded(name), bounded(uri), attributes)
+				.expect("genesis game owners must be able to pay the metadata deposit");
+		}
+	}
+
+	/// Mints `count` games for `owner`. The caller's transaction is reverted if any mint fails.
+	pub fn do_create_games(owner: T::AccountId, count: u32, royalty: Permill) -> DispatchResult {
+		ensure!(count > 0, Error::<T>::EmptyBatch);
+		ensure!(count <= T::MaxBatchSize::get(), Error::<T>::BatchTooLarge);
+		for _ in 0..count {
+			Self::mint(owner.clone(), Self::gen_game_key(), royalty, true)?;
+		}
+
+		Self::deposit_event(Event::<T>::GamesCreated { owner, count });
+		Ok(())
+	}
+
+	/// Moves every game in `transfers` from `from` to its paired recipient. The caller's
+	/// transaction is reverted if any transfer fails.
+	pub fn do_transfer_games(
+		from: T::AccountId,
+		transfers: Vec<(T::AccountId, [u8; 32])>,
+	) -> DispatchResult {
+		ensure!(!transfers.is_empty(), Error::<T>::EmptyBatch);
+		let count = transfers.len() as u32;
+		for (to, id) in transfers {
+			Self::do_transfer(from.clone(), to, id)?;
+		}
+
+		Self::deposit_event(Event::<T>::GamesTransferred { from, count });
+		Ok(())
+	}
+
+	/// Destroys the game, releasing its offers and refunding its metadata deposit. The owner and
+	/// anyone allowed to move the game may burn it.
+	pub fn do_burn(who: T::AccountId, id: [u8; 32]) -> DispatchResult {
+		let game = Games::<T>::get(id).ok_or(Error::<T>::GameNotFound)?;
+		ensure!(Self::can_move(&who, &game.owner, &id), Error::<T>::NotAuthorized);
+		Self::ensure_unlocked(&id)?;
+		Self::ensure_not_rented(&game)?;
+
+		Games::<T>::remove(id);
+		GamesOwnedBy::<T>::remove(&game.owner, id);
+		LiveGameCount::<T>::mutate(|count| count.saturating_dec());
+		Approvals::<T>::remove(id);
+		RentalOffers::<T>::remove(id);
+		Self::clear_listing_expiry(id);
+		Self::clear_offers(id);
+		Self::cancel_swaps(id);
+		Self::remove_metadata(id);
+
+		Self::deposit_event(Event::<T>::GameBurned { owner: game.owner, id });
+		Ok(())
+	}
+
+	/// Lists the game at `price` until block `expiry`, or delists it when `price` is `None`. The
+	/// expiry is ignored for a delisting.
 	pub fn do_set_price(
 		from: T::AccountId,
 		id: [u8; 32],
 		price: Option<T::Balance>,
+		expiry: Option<BlockNumberFor<T>>,
 	) -> DispatchResult {
 		let mut game = Games::<T>::get(id).ok_or(Error::<T>::GameNotFound)?;
 		ensure!(game.owner == from, Error::<T>::NotAuthorized);
+		ensure!(price.is_none() || game.transferable, Error::<T>::Soulbound);
+		Self::ensure_unlocked(&id)?;
+		Self::ensure_not_rented(&game)?;
 
+		Self::clear_listing_expiry(id);
+		let expiry = expiry.filter(|_| price.is_some());
+		if let Some(expiry) = expiry {
+			ensure!(
+				expiry > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::ListingExpiryInPast
+			);
+			ListingsExpiringAt::<T>::try_mutate(expiry, |expiring| {
+				expiring.try_push(id).map_err(|_| Error::<T>::TooManyListingsExpiring)
+			})?;
+			ListingExpiry::<T>::insert(id, expiry);
+			// The sweep starts no later than the first block a listing was given an expiry in.
+			if !ListingSweepCursor::<T>::exists() {
+				ListingSweepCursor::<T>::put(frame_system::Pallet::<T>::block_number());
+			}
+		}
 		game.value = price;
 		Games::<T>::insert(id, game);
 
-		Self::deposit_event(Event::<T>::PriceUpdated { owner: from, id, price });
+		Self::deposit_event(Event::<T>::PriceUpdated { owner: from, id, price, expiry });
 		Ok(())
 	}
 
+	/// Delists every listing expiring at or before block `now`, oldest block first, for as long
+	/// as `limit` leaves room for another full block of expiries.
+	pub fn sweep_listings(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+		let mut used = T::DbWeight::get().reads_writes(1, 1);
+		let worst = <T as Config>::WeightInfo::expire_listings(T::MaxListingsPerBlock::get());
+		if !limit.all_gte(used.saturating_add(worst)) {
+			return Weight::zero();
+		}
+
+		// Without a cursor no listing has ever had an expiry, so there is nothing to sweep.
+		let Some(mut cursor) = ListingSweepCursor::<T>::get() else {
+			return T::DbWeight::get().reads(1);
+		};
+		while cursor <= now && limit.all_gte(used.saturating_add(worst)) {
+			used.saturating_accrue(Self::expire_listings(cursor));
+			cursor.saturating_inc();
+		}
+		ListingSweepCursor::<T>::put(cursor);
+		used
+	}
+
+	/// Delists every listing expiring at block `n`.
+	pub fn expire_listings(n: BlockNumberFor<T>) -> Weight {
+		let expiring = ListingsExpiringAt::<T>::take(n);
+		for id in expiring.iter() {
+			ListingExpiry::<T>::remove(id);
+			Games::<T>::mutate(id, |maybe_game| {
+				if let Some(game) = maybe_game {
+					game.value = None;
+				}
+			});
+			Self::deposit_event(Event::<T>::ListingExpired { id: *id });
+		}
+		<T as Config>::WeightInfo::expire_listings(expiring.len() as u32)
+	}
+
+	fn clear_listing_expiry(id: [u8; 32]) {
+		if let Some(expiry) = ListingExpiry::<T>::take(id) {
+			ListingsExpiringAt::<T>::mutate(expiry, |expiring| expiring.retain(|x| *x != id));
+		}
+	}
+
 	pub fn do_buy_game(buyer: T::AccountId, id: [u8; 32], max_price: T::Balance) -> DispatchResult {
 		let game = Games::<T>::get(id).ok_or(Error::<T>::GameNotFound)?;
 
-		let price = game.value.ok_or(Error::<T>::NotForSale)?;
+		let price = Self::current_price(id).ok_or(Error::<T>::NotForSale)?;
 		ensure!(price <= max_price, Error::<T>::PriceTooLow);
 		ensure!(game.owner != buyer, Error::<T>::TransferToSelf);
 
-		pallet_balances::Pallet::<T>::transfer(&buyer, &game.owner, price, KeepAlive)?;
+		Self::pay_proceeds(id, &game, &buyer, price, None)?;
+		DutchAuctions::<T>::remove(id);
 		Self::do_transfer(game.owner.clone(), buyer.clone(), id)?;
-		Self::do_set_price(buyer.clone(), id, None)?;
 
 		Self::deposit_event(Event::<T>::GameSold { buyer, id, price });
 		Ok(())
 	}
+
+	/// Approves `delegate` to move the game, or clears the approval when it is `None`. The owner
+	/// and the owner's operators may do this.
+	pub fn do_approve(
+		who: T::AccountId,
+		id: [u8; 32],
+		delegate: Option<T::AccountId>,
+	) -> DispatchResult {
+		let game = Games::<T>::get(id).ok_or(Error::<T>::GameNotFound)?;
+		ensure!(
+			game.owner == who || Operators::<T>::contains_key(&game.owner, &who),
+			Error::<T>::NotAuthorized
+		);
+
+		match &delegate {
+			Some(delegate) => Approvals::<T>::insert(id, delegate),
+			None => Approvals::<T>::remove(id),
+		}
+
+		Self::deposit_event(Event::<T>::Approval { owner: game.owner, delegate, id });
+		Ok(())
+	}
+
+	pub fn do_set_operator(
+		owner: T::AccountId,
+		operator: T::AccountId,
+		approved: bool,
+	) -> DispatchResult {
+		ensure!(owner != operator, Error::<T>::TransferToSelf);
+		if approved {
+			Operators::<T>::insert(&owner, &operator, ());
+		} else {
+			Operators::<T>::remove(&owner, &operator);
+		}
+
+		Self::deposit_event(Event::<T>::OperatorSet { owner, operator, approved });
+		Ok(())
+	}
+
+	/// Whether `who` may move `from`'s game: as the owner, through an approval for the game, or as
+	/// one of `from`'s operators.
+	pub fn can_move(who: &T::AccountId, from: &T::AccountId, id: &[u8; 32]) -> bool {
+		who == from
+			|| Approvals::<T>::get(id).as_ref() == Some(who)
+			|| Operators::<T>::contains_key(from, who)
+	}
+
+	/// The price a buyer would pay for the game right now, if it is for sale.
+	pub fn current_price(id: [u8; 32]) -> Option<T::Balance> {
+		match DutchAuctions::<T>::get(id) {
+			Some(auction) => Some(Self::dutch_price(&auction)),
+			None => {
+				let game = Games::<T>::get(id)?;
+				// An expired listing is no longer buyable, even before `on_idle` delists it.
+				let now = frame_system::Pallet::<T>::block_number();
+				if ListingExpiry::<T>::get(id).is_some_and(|expiry| expiry <= now) {
+					return None;
+				}
+				game.value
+			},
+		}
+	}
+
+	pub fn game_info(id: [u8; 32]) -> Option<GameInfo<T::AccountId, T::Balance>> {
+		let game = Games::<T>::get(id)?;
+		Some(GameInfo {
+			id,
+			owner: game.owner,
+			creator: game.creator,
+			royalty: game.royalty,
+			price: Self::current_price(id),
+		})
+	}
+
+	/// Up to `limit` of the games `owner` holds, skipping the first `offset`.
+	pub fn games_of(
+		owner: T::AccountId,
+		offset: u32,
+		limit: u32,
+	) -> Vec<GameInfo<T::AccountId, T::Balance>> {
+		GamesOwnedBy::<T>::iter_key_prefix(owner)
+			.skip(offset as usize)
+			.take(limit as usize)
+			.filter_map(Self::game_info)
+			.collect()
+	}
+
+	/// Up to `limit` of the games for sale, cheapest first, skipping the first `offset`.
+	///
+	/// There is no on-chain index by price, since Dutch auction prices change every block, so
+	/// this reads games in storage order and sorts the listed ones. At most `MaxListingsScanned`
+	/// games are read, so once there are more games than that some listings are left out;
+	/// `listings_from` pages through all of them.
+	pub fn listings(offset: u32, limit: u32) -> Vec<GameInfo<T::AccountId, T::Balance>> {
+		let mut listed: Vec<_> = Games::<T>::iter_keys()
+			.take(T::MaxListingsScanned::get() as usize)
+			.filter_map(Self::game_info)
+			.filter(|game| game.price.is_some())
+			.collect();
+		listed.sort_by_key(|game| (game.price, game.id));
+		listed.into_iter().skip(offset as usize).take(limit as usize).collect()
+	}
+
+	/// Up to `limit` of the games for sale, in storage order, starting after the game `start`.
+	///
+	/// A page reads at most `MaxListingsScanned` games. While games are left to read it returns
+	/// the key to continue from, so a short page is never mistaken for the end.
+	pub fn listings_from(
+		start: Option<[u8; 32]>,
+		limit: u32,
+	) -> Listings<T::AccountId, T::Balance> {
+		let mut keys = match start {
+			Some(id) => Games::<T>::iter_keys_from(Games::<T>::hashed_key_for(id)),
+			None => Games::<T>::iter_keys(),
+		};
+		let mut games = Vec::new();
+		let mut last = start;
+		let mut scanned = 0;
+		while games.len() < limit as usize && scanned < T::MaxListingsScanned::get() {
+			let Some(id) = keys.next() else {
+				return Listings { games, next: None };
+			};
+			scanned += 1;
+			last = Some(id);
+			games.extend(Self::game_info(id).filter(|game| game.price.is_some()));
+		}
+		let next = last.filter(|_| keys.next().is_some());
+		Listings { games, next }
+	}
+
+	pub fn supply() -> Supply {
+		Supply { total: GameCount::<T>::get(), live: LiveGameCount::<T>::get() }
+	}
+
+	fn dutch_price(auction: &DutchAuction<T>) -> T::Balance {
+		let now = frame_system::Pallet::<T>::block_number();
+		let elapsed = now.saturating_sub(auction.start).min(auction.duration);
+		let decay = Perbill::from_rational(elapsed, auction.duration)
+			.mul_floor(auction.start_price.saturating_sub(auction.floor_price));
+		auction.start_price.saturating_sub(decay)
+	}
+
+	/// Offers `renter` the use of the game until block `until`, for `fee` if there is one.
+	pub fn do_lend(
+		owner: T::AccountId,
+		id: [u8; 32],
+		renter: T::AccountId,
+		until: BlockNumberFor<T>,
+		fee: Option<T::Balance>,
+	) -> DispatchResult {
+		let game = Games::<T>::get(id).ok_or(Error::<T>::GameNotFound)?;
+		ensure!(game.owner == owner, Error::<T>::NotAuthorized);
+		ensure!(renter != owner, Error::<T>::TransferToSelf);
+		Self::ensure_transferable(&game)?;
+		Self::ensure_unlocked(&id)?;
+		Self::ensure_not_rented(&game)?;
+		ensure!(until > frame_system::Pallet::<T>::block_number(), Error::<T>::RentalEndInPast);
+
+		match fee {
+			None => Self::start_rental(id, renter, until),
+			Some(fee) => {
+				RentalOffers::<T>::insert(id, RentalTerms { renter: renter.clone(), until, fee });
+				Self::deposit_event(Event::<T>::RentalOffered { id, renter, until, fee });
+				Ok(())
+			},
+		}
+	}
+
+	/// Pays the owner the fee the renter was offered and starts the rental.
+	pub fn do_accept_rental(renter: T::AccountId, id: [u8; 32]) -> DispatchResult {
+		let terms = RentalOffers::<T>::take(id).ok_or(Error::<T>::RentalNotFound)?;
+		ensure!(terms.renter == renter, Error::<T>::NotAuthorized);
+		ensure!(
+			terms.until > frame_system::Pallet::<T>::block_number(),
+			Error::<T>::RentalEndInPast
+		);
+		let game = Games::<T>::get(id).ok_or(Error::<T>::GameNotFound)?;
+		// An auction or another rental may have started since the rental was offered.
+		Self::ensure_unlocked(&id)?;
+		Self::ensure_not_rented(&game)?;
+
+		Self::pay(&renter, &game.owner, terms.fee, None)?;
+		Self::start_rental(id, renter, terms.until)
+	}
+
+	/// Hands the game to `renter` until block `until`, taking it off sale.
+	fn start_rental(
+		id: [u8; 32],
+		renter: T::AccountId,
+		until: BlockNumberFor<T>,
+	) -> DispatchResult {
+		Self::clear_listing_expiry(id);
+		RentalOffers::<T>::remove(id);
+		Games::<T>::try_mutate(id, |maybe_game| {
+			let game = maybe_game.as_mut().ok_or(Error::<T>::GameNotFound)?;
+			game.value = None;
+			game.rental = Some(Rental { renter: renter.clone(), until });
+			Ok::<_, DispatchError>(())
+		})?;
+
+		Self::deposit_event(Event::<T>::RentalStarted { id, renter, until });
+		Ok(())
+	}
+
+	/// The account entitled to use the game: its renter while a rental runs, otherwise its owner.
+	pub fn current_user(id: [u8; 32]) -> Option<T::AccountId> {
+		let game = Games::<T>::get(id)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		Some(match game.rental {
+			Some(rental) if rental.until > now => rental.renter,
+			_ => game.owner,
+		})
+	}
+
+	/// Fails with `GameRented` until the game's rental ends.
+	fn ensure_not_rented(game: &Game<T>) -> DispatchResult {
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(
+			game.rental.as_ref().is_none_or(|rental| rental.until <= now),
+			Error::<T>::GameRented
+		);
+		Ok(())
+	}
+
+	/// Fails with `Soulbound` for games that can never change owner.
+	fn ensure_transferable(game: &Game<T>) -> DispatchResult {
+		ensure!(game.transferable, Error::<T>::Soulbound);
+		Ok(())
+	}
+
+	/// Fails with `GameLocked` while the game is held by a running auction or a bundle.
+	pub fn ensure_unlocked(id: &[u8; 32]) -> DispatchResult {
+		ensure!(!Auctions::<T>::contains_key(id), Error::<T>::GameLocked);
+		ensure!(!DutchAuctions::<T>::contains_key(id), Error::<T>::GameLocked);
+		ensure!(!SealedAuctions::<T>::contains_key(id), Error::<T>::GameLocked);
+		ensure!(!BundledGames::<T>::contains_key(id), Error::<T>::GameLocked);
+		Ok(())
+	}
+
+	pub fn do_create_auction(
+		seller: T::AccountId,
+		id: [u8; 32],
+		reserve: T::Balance,
+		end: BlockNumberFor<T>,
+	) -> DispatchResult {
+		let game = Games::<T>::get(id).ok_or(Error::<T>::GameNotFound)?;
+		ensure!(game.owner == seller, Error::<T>::NotAuthorized);
+		Self::ensure_transferable(&game)?;
+		Self::ensure_unlocked(&id)?;
+		Self::ensure_not_rented(&game)?;
+		ensure!(end > frame_system::Pallet::<T>::block_number(), Error::<T>::AuctionEndInPast);
+
+		if game.value.is_some() {
+			Self::do_set_price(seller.clone(), id, None, None)?;
+		}
+		AuctionsEndingAt::<T>::try_mutate(end, |ending| {
+			ending.try_push(id).map_err(|_| Error::<T>::TooManyAuctionsEnding)
+		})?;
+		Auctions::<T>::insert(id, Auction { seller, reserve, end, highest_bid: None });
+
+		Self::deposit_event(Event::<T>::AuctionStarted { id, reserve, end });
+		Ok(())
+	}
+
+	pub fn do_create_dutch_auction(
+		seller: T::AccountId,
+		id: [u8; 32],
+		start_price: T::Balance,
+		floor_price: T::Balance,
+		duration: BlockNumberFor<T>,
+	) -> DispatchResult {
+		let game = Games::<T>::get(id).ok_or(Error::<T>::GameNotFound)?;
+		ensure!(game.owner == seller, Error::<T>::NotAuthorized);
+		Self::ensure_transferable(&game)?;
+		Self::ensure_unlocked(&id)?;
+		Self::ensure_not_rented(&game)?;
+		ensure!(
+			floor_price <= start_price && !duration.is_zero(),
+			Error::<T>::InvalidPriceSchedule
+		);
+
+		if game.value.is_some() {
+			Self::do_set_price(seller.clone(), id, None, None)?;
+		}
+		let start = frame_system::Pallet::<T>::block_number();
+		DutchAuctions::<T>::insert(
+			id,
+			DutchAuction { seller, start_price, floor_price, start, duration },
+		);
+
+		Self::deposit_event(Event::<T>::DutchAuctionStarted { id, start_price, floor_price });
+		Ok(())
+	}
+
+	pub fn do_bid(bidder: T::AccountId, id: [u8; 32], amount: T::Balance) -> DispatchResult {
+		let mut auction = Auctions::<T>::get(id).ok_or(Error::<T>::AuctionNotFound)?;
+		ensure!(frame_system::Pallet::<T>::block_number() < auction.end, Error::<T>::AuctionClosed);
+		ensure!(bidder != auction.seller, Error::<T>::TransferToSelf);
+		ensure!(amount >= auction.reserve, Error::<T>::BidTooLow);
+
+		if let Some((outbid, outbid_amount)) = auction.highest_bid.take() {
+			ensure!(amount > outbid_amount, Error::<T>::BidTooLow);
+			T::Currency::release(
+				&HoldReason::AuctionBid.into(),
+				&outbid,
+				outbid_amount,
+				Precision::Exact,
+			)?;
+		}
+		T::Currency::hold(&HoldReason::AuctionBid.into(), &bidder, amount)?;
+
+		auction.highest_bid = Some((bidder.clone(), amount));
+		Auctions::<T>::insert(id, auction);
+
+		Self::deposit_event(Event::<T>::BidPlaced { bidder, id, amount });
+		Ok(())
+	}
+
+	pub fn do_cancel_auction(seller: T::AccountId, id: [u8; 32]) -> DispatchResult {
+		if let Some(auction) = DutchAuctions::<T>::get(id) {
+			ensure!(auction.seller == seller, Error::<T>::NotAuthorized);
+			DutchAuctions::<T>::remove(id);
+			Self::deposit_event(Event::<T>::AuctionCancelled { id });
+			return Ok(());
+		}
+
+		if let Some(auction) = SealedAuctions::<T>::get(id) {
+			ensure!(auction.seller == seller, Error::<T>::NotAuthorized);
+			ensure!(!SealedBids::<T>::contains_key(id), Error::<T>::AuctionHasBids);
+			SealedAuctionsEndingAt::<T>::mutate(auction.reveal_end, |ending| {
+				ending.retain(|x| *x != id)
+			});
+			SealedAuctions::<T>::remove(id);
+			Self::deposit_event(Event::<T>::AuctionCancelled { id });
+			return Ok(());
+		}
+
+		let auction = Auctions::<T>::get(id).ok_or(Error::<T>::AuctionNotFound)?;
+		ensure!(auction.seller == seller, Error::<T>::NotAuthorized);
+		ensure!(auction.highest_bid.is_none(), Error::<T>::AuctionHasBids);
+
+		AuctionsEndingAt::<T>::mutate(auction.end, |ending| ending.retain(|x| *x != id));
+		Auctions::<T>::remove(id);
+
+		Self::deposit_event(Event::<T>::AuctionCancelled { id });
+		Ok(())
+	}
+
+	/// Settles auctions that ended at or before block `now`, oldest block first, for as long as
+	/// `limit` leaves room for another settlement. Auctions that do not fit stay indexed and are
+	/// settled in a later block.
+	pub fn settle_auctions(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+		let mut used = T::DbWeight::get().reads_writes(1, 1);
+		let worst = <T as Config>::WeightInfo::settle_auction();
+
+		// Without a cursor every earlier block was settled in full as it started.
+		let mut cursor = AuctionSettlementCursor::<T>::get().unwrap_or(now);
+		while cursor <= now && limit.all_gte(used.saturating_add(worst)) {
+			if Self::settle_next_auction(cursor) {
+				used.saturating_accrue(worst);
+			} else {
+				used.saturating_accrue(T::DbWeight::get().reads(1));
+				cursor.saturating_inc();
+			}
+		}
+		AuctionSettlementCursor::<T>::put(cursor);
+		used
+	}
+
+	/// Settles one auction ending at block `n`. Returns whether there was one. An auction whose
+	/// settlement fails ends without a sale and its highest bid is released.
+	pub fn settle_next_auction(n: BlockNumberFor<T>) -> bool {
+		let mut ending = AuctionsEndingAt::<T>::get(n);
+		if ending.is_empty() {
+			return false;
+		}
+		let id = ending.remove(0);
+		if ending.is_empty() {
+			AuctionsEndingAt::<T>::remove(n);
+		} else {
+			AuctionsEndingAt::<T>::insert(n, ending);
+		}
+
+		if with_storage_layer(|| Self::settle_auction(id)).is_err() {
+			Self::abort_auction(id);
+		}
+		true
+	}
+
+	fn settle_auction(id: [u8; 32]) -> DispatchResult {
+		let auction = Auctions::<T>::take(id).ok_or(Error::<T>::AuctionNotFound)?;
+		let Some((winner, price)) = auction.highest_bid else {
+			Self::deposit_event(Event::<T>::AuctionEnded { id });
+			return Ok(());
+		};
+
+		let game = Games::<T>::get(id).ok_or(Error::<T>::GameNotFound)?;
+		Self::pay_proceeds(id, &game, &winner, price, Some(HoldReason::AuctionBid))?;
+		Self::do_transfer(auction.seller, winner.clone(), id)?;
+
+		Self::deposit_event(Event::<T>::AuctionSettled { winner, id, price });
+		Ok(())
+	}
+
+	/// The commitment a sealed bid of `amount` by `bidder` is submitted under. Including the
+	/// bidder stops others from copying a commitment and revealing it once the salt is public.
+	pub fn sealed_bid_commitment(
+		bidder: &T::AccountId,
+		amount: T::Balance,
+		salt: [u8; 32],
+	) -> [u8; 32] {
+		BlakeTwo256::hash_of(&(bidder, amount, salt)).into()
+	}
+
+	pub fn do_create_sealed_auction(
+		seller: T::AccountId,
+		id: [u8; 32],
+		reserve: T::Balance,
+		commit_end: BlockNumberFor<T>,
+		reveal_end: BlockNumberFor<T>,
+	) -> DispatchResult {
+		let game = Games::<T>::get(id).ok_or(Error::<T>::GameNotFound)?;
+		ensure!(game.owner == seller, Error::<T>::NotAuthorized);
+		Self::ensure_transferable(&game)?;
+		Self::ensure_unlocked(&id)?;
+		Self::ensure_not_rented(&game)?;
+		ensure!(
+			commit_end > frame_system::Pallet::<T>::block_number(),
+			Error::<T>::AuctionEndInPast
+		);
+		ensure!(reveal_end > commit_end, Error::<T>::InvalidAuctionPhases);
+
+		if game.value.is_some() {
+			Self::do_set_price(seller.clone(), id, None, None)?;
+		}
+		SealedAuctionsEndingAt::<T>::try_mutate(reveal_end, |ending| {
+			ending.try_push(id).map_err(|_| Error::<T>::TooManyAuctionsEnding)
+		})?;
+		SealedAuctions::<T>::insert(
+			id,
+			SealedAuction { seller, reserve, commit_end, reveal_end, highest_bid: None },
+		);
+
+		Self::deposit_event(Event::<T>::SealedAuctionStarted {
+			id,
+			reserve,
+			commit_end,
+			reveal_end,
+		});
+		Ok(())
+	}
+
+	pub fn do_commit_bid(
+		bidder: T::AccountId,
+		id: [u8; 32],
+		commitment: [u8; 32],
+		deposit: T::Balance,
+	) -> DispatchResult {
+		let auction = SealedAuctions::<T>::get(id).ok_or(Error::<T>::AuctionNotFound)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() < auction.commit_end,
+			Error::<T>::AuctionClosed
+		);
+		ensure!(bidder != auction.seller, Error::<T>::TransferToSelf);
+		ensure!(deposit >= auction.reserve, Error::<T>::BidTooLow);
+
+		SealedBids::<T>::try_mutate(id, |bids| {
+			ensure!(!bids.iter().any(|bid| bid.bidder == bidder), Error::<T>::BidAlreadyCommitted);
+			bids.try_push(SealedBid {
+				bidder: bidder.clone(),
+				commitment,
+				deposit,
+				revealed: false,
+			})
+			.map_err(|_| Error::<T>::TooManySealedBids)
+		})?;
+		T::Currency::hold(&HoldReason::SealedBid.into(), &bidder, deposit)?;
+
+		Self::deposit_event(Event::<T>::BidCommitted { bidder, id, deposit });
+		Ok(())
+	}
+
+	/// Reveals a committed bid. A bid that does not beat the highest one revealed so far, ties
+	/// included, gets its deposit back at once.
+	pub fn do_reveal_bid(
+		bidder: T::AccountId,
+		id: [u8; 32],
+		amount: T::Balance,
+		salt: [u8; 32],
+	) -> DispatchResult {
+		let mut auction = SealedAuctions::<T>::get(id).ok_or(Error::<T>::AuctionNotFound)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(now >= auction.commit_end && now < auction.reveal_end, Error::<T>::NotRevealing);
+
+		SealedBids::<T>::try_mutate(id, |bids| -> DispatchResult {
+			let index = bids
+				.iter()
+				.position(|bid| bid.bidder == bidder)
+				.ok_or(Error::<T>::BidNotFound)?;
+			let bid = &mut bids[index];
+			ensure!(!bid.revealed, Error::<T>::BidAlreadyRevealed);
+			ensure!(
+				Self::sealed_bid_commitment(&bidder, amount, salt) == bid.commitment,
+				Error::<T>::CommitmentMismatch
+			);
+			ensure!(amount <= bid.deposit, Error::<T>::BidExceedsDeposit);
+			ensure!(amount >= auction.reserve, Error::<T>::BidTooLow);
+			bid.revealed = true;
+
+			let loser = match auction.highest_bid.take() {
+				Some((highest, highest_amount)) if amount <= highest_amount => {
+					auction.highest_bid = Some((highest, highest_amount));
+					Some(bidder.clone())
+				},
+				outbid => {
+					auction.highest_bid = Some((bidder.clone(), amount));
+					outbid.map(|(outbid, _)| outbid)
+				},
+			};
+			if let Some(loser) = loser {
+				let deposit = bids
+					.iter()
+					.find(|bid| bid.bidder == loser)
+					.map(|bid| bid.deposit)
+					.ok_or(Error::<T>::BidNotFound)?;
+				T::Currency::release(
+					&HoldReason::SealedBid.into(),
+					&loser,
+					deposit,
+					Precision::Exact,
+				)?;
+			}
+			Ok(())
+		})?;
+		SealedAuctions::<T>::insert(id, auction);
+
+		Self::deposit_event(Event::<T>::BidRevealed { bidder, id, amount });
+		Ok(())
+	}
+
+	/// Settles sealed auctions whose reveal phase ended at or before block `now`, oldest block
+	/// first, for as long as `limit` leaves room for another settlement. Auctions that do not fit
+	/// stay indexed and are settled in a later block.
+	pub fn settle_sealed_auctions(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+		let mut used = T::DbWeight::get().reads_writes(1, 1);
+		let worst = <T as Config>::WeightInfo::settle_sealed_auction();
+
+		// Without a cursor every earlier block was settled in full as it started.
+		let mut cursor = SealedAuctionSettlementCursor::<T>::get().unwrap_or(now);
+		while cursor <= now && limit.all_gte(used.saturating_add(worst)) {
+			if Self::settle_next_sealed_auction(cursor) {
+				used.saturating_accrue(worst);
+			} else {
+				used.saturating_accrue(T::DbWeight::get().reads(1));
+				cursor.saturating_inc();
+			}
+		}
+		SealedAuctionSettlementCursor::<T>::put(cursor);
+		used
+	}
+
+	/// Settles one sealed auction whose reveal phase ends at block `n`. Returns whether there
+	/// was one. An auction whose settlement fails ends without a sale.
+	pub fn settle_next_sealed_auction(n: BlockNumberFor<T>) -> bool {
+		let mut ending = SealedAuctionsEndingAt::<T>::get(n);
+		if ending.is_empty() {
+			return false;
+		}
+		let id = ending.remove(0);
+		if ending.is_empty() {
+			SealedAuctionsEndingAt::<T>::remove(n);
+		} else {
+			SealedAuctionsEndingAt::<T>::insert(n, ending);
+		}
+
+		if with_storage_layer(|| Self::settle_sealed_auction(id)).is_err() {
+			Self::abort_sealed_auction(id);
+		}
+		true
+	}
+
+	fn settle_sealed_auction(id: [u8; 32]) -> DispatchResult {
+		let auction = SealedAuctions::<T>::take(id).ok_or(Error::<T>::AuctionNotFound)?;
+		let bids = SealedBids::<T>::take(id);
+		for bid in bids.iter().filter(|bid| !bid.revealed) {
+			Self::settle_unrevealed_bid(id, bid);
+		}
+		let Some((winner, price)) = auction.highest_bid else {
+			Self::deposit_event(Event::<T>::AuctionEnded { id });
+			return Ok(());
+		};
+
+		let deposit = bids
+			.iter()
+			.find(|bid| bid.bidder == winner)
+			.map(|bid| bid.deposit)
+			.ok_or(Error::<T>::BidNotFound)?;
+		let game = Games::<T>::get(id).ok_or(Error::<T>::GameNotFound)?;
+		Self::pay_proceeds(id, &game, &winner, price, Some(HoldReason::SealedBid))?;
+		T::Currency::release(
+			&HoldReason::SealedBid.into(),
+			&winner,
+			deposit.saturating_sub(price),
+			Precision::Exact,
+		)?;
+		Self::do_transfer(auction.seller, winner.clone(), id)?;
+
+		Self::deposit_event(Event::<T>::AuctionSettled { winner, id, price });
+		Ok(())
+	}
+
+	/// Refunds or slashes the deposit behind a bid that was never revealed.
+	fn settle_unrevealed_bid(id: [u8; 32], bid: &SealedBid<T>) {
+		let reason = HoldReason::SealedBid.into();
+		let slashed = T::UnrevealedBidPolicy::get() == UnrevealedBidPolicy::Slash;
+		let settled = if slashed {
+			T::Currency::burn_held(
+				&reason,
+				&bid.bidder,
+				bid.deposit,
+				Precision::BestEffort,
+				Fortitude::Force,
+			)
+		} else {
+			T::Currency::release(&reason, &bid.bidder, bid.deposit, Precision::BestEffort)
+		};
+		defensive_assert!(settled.is_ok(), "every unrevealed deposit is held; qed");
+
+		Self::deposit_event(Event::<T>::UnrevealedBidSettled {
+			bidder: bid.bidder.clone(),
+			id,
+			deposit: bid.deposit,
+			slashed,
+		});
+	}
+
+	pub fn do_make_offer(
+		buyer: T::AccountId,
+		id: [u8; 32],
+		amount: T::Balance,
+		expiry: BlockNumberFor<T>,
+	) -> DispatchResult {
+		let game = Games::<T>::get(id).ok_or(Error::<T>::GameNotFound)?;
+		ensure!(game.owner != buyer, Error::<T>::TransferToSelf);
+		Self::ensure_transferable(&game)?;
+		ensure!(amount >= T::MinOffer::get(), Error::<T>::OfferTooLow);
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(expiry > now, Error::<T>::OfferExpiryInPast);
+		ensure!(
+			expiry <= now.saturating_add(T::MaxOfferDuration::get()),
+			Error::<T>::OfferExpiryTooFar
+		);
+
+		let outbid = Offers::<T>::try_mutate(id, |offers| {
+			ensure!(!offers.iter().any(|offer| offer.buyer == buyer), Error::<T>::OfferExists);
+			// A game with the most offers it can take only accepts one that beats the lowest,
+			// which makes way for it.
+			let mut outbid = None;
+			if offers.is_full() {
+				let (index, lowest) = offers
+					.iter()
+					.enumerate()
+					.min_by_key(|(_, offer)| offer.amount)
+					.ok_or(Error::<T>::TooManyOffers)?;
+				ensure!(amount > lowest.amount, Error::<T>::TooManyOffers);
+				outbid = Some(offers.remove(index));
+			}
+			offers
+				.try_push(Offer { buyer: buyer.clone(), amount, expiry })
+				.map_err(|_| Error::<T>::TooManyOffers)?;
+			Ok::<_, DispatchError>(outbid)
+		})?;
+		if let Some(offer) = &outbid {
+			Self::unindex_offer(id, offer);
+		}
+		OffersExpiringAt::<T>::try_mutate(expiry, |expiring| {
+			expiring
+				.try_push((id, buyer.clone()))
+				.map_err(|_| Error::<T>::TooManyOffersExpiring)
+		})?;
+		T::Currency::hold(&HoldReason::Offer.into(), &buyer, amount)?;
+		if let Some(offer) = outbid {
+			Self::release_offer(id, offer);
+		}
+
+		Self::deposit_event(Event::<T>::OfferMade { buyer, id, amount });
+		Ok(())
+	}
+
+	pub fn do_withdraw_offer(buyer: T::AccountId, id: [u8; 32]) -> DispatchResult {
+		let offer = Self::take_offer(id, &buyer)?;
+		Self::unindex_offer(id, &offer);
+		Self::release_offer(id, offer);
+		Ok(())
+	}
+
+	pub fn do_accept_offer(
+		owner: T::AccountId,
+		id: [u8; 32],
+		buyer: T::AccountId,
+	) -> DispatchResult {
+		let game = Games::<T>::get(id).ok_or(Error::<T>::GameNotFound)?;
+		ensure!(game.owner == owner, Error::<T>::NotAuthorized);
+
+		let offer = Self::take_offer(id, &buyer)?;
+		Self::unindex_offer(id, &offer);
+		Self::pay_proceeds(id, &game, &buyer, offer.amount, Some(HoldReason::Offer))?;
+		Self::do_transfer(owner, buyer.clone(), id)?;
+
+		Self::deposit_event(Event::<T>::OfferAccepted { buyer, id, amount: offer.amount });
+		Ok(())
+	}
+
+	/// Releases every offer expiring at block `n`.
+	pub fn expire_offers(n: BlockNumberFor<T>) -> Weight {
+		let expiring = OffersExpiringAt::<T>::take(n);
+		for (id, buyer) in expiring.iter() {
+			match Self::take_offer(*id, buyer) {
+				Ok(offer) => Self::release_offer(*id, offer),
+				Err(_) => {
+					defensive!("every indexed offer is stored; qed");
+				},
+			}
+		}
+		<T as Config>::WeightInfo::expire_offers(expiring.len() as u32)
+	}
+
+	/// Releases every open offer on the game, e.g. because it changed hands.
+	fn clear_offers(id: [u8; 32]) {
+		for offer in Offers::<T>::take(id) {
+			Self::unindex_offer(id, &offer);
+			Self::release_offer(id, offer);
+		}
+	}
+
+	/// Removes `buyer`'s offer on the game without touching the expiry index.
+	fn take_offer(id: [u8; 32], buyer: &T::AccountId) -> Result<Offer<T>, DispatchError> {
+		Offers::<T>::try_mutate_exists(id, |maybe_offers| {
+			let offers = maybe_offers.as_mut().ok_or(Error::<T>::OfferNotFound)?;
+			let index = offers
+				.iter()
+				.position(|offer| offer.buyer == *buyer)
+				.ok_or(Error::<T>::OfferNotFound)?;
+			let offer = offers.remove(index);
+			if offers.is_empty() {
+				*maybe_offers = None;
+			}
+			Ok(offer)
+		})
+	}
+
+	fn unindex_offer(id: [u8; 32], offer: &Offer<T>) {
+		OffersExpiringAt::<T>::mutate(offer.expiry, |expiring| {
+			expiring.retain(|(game, buyer)| *game != id || *buyer != offer.buyer)
+		});
+	}
+
+	fn release_offer(id: [u8; 32], offer: Offer<T>) {
+		let released = T::Currency::release(
+			&HoldReason::Offer.into(),
+			&offer.buyer,
+			offer.amount,
+			Precision::BestEffort,
+		);
+		defensive_assert!(released.is_ok(), "every offer is backed by a hold; qed");
+		Self::deposit_event(Event::<T>::OfferReleased { buyer: offer.buyer, id });
+	}
+
+	/// Records a proposal to trade `proposer`'s `give` games for `counterparty`'s `want` games.
+	/// Ownership is checked again when the swap is accepted.
+	pub fn do_propose_swap(
+		proposer: T::AccountId,
+		counterparty: T::AccountId,
+		give: SwapGames<T>,
+		want: SwapGames<T>,
+		top_up: Option<TopUp<T::Balance>>,
+		expiry: BlockNumberFor<T>,
+	) -> DispatchResult {
+		ensure!(proposer != counterparty, Error::<T>::TransferToSelf);
+		ensure!(!give.is_empty() || !want.is_empty(), Error::<T>::EmptySwap);
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(expiry > now, Error::<T>::SwapExpiryInPast);
+		ensure!(
+			expiry <= now.saturating_add(T::MaxSwapDuration::get()),
+			Error::<T>::SwapExpiryTooFar
+		);
+
+		let mut games: Vec<[u8; 32]> = give.iter().chain(want.iter()).copied().collect();
+		games.sort();
+		ensure!(games.windows(2).all(|pair| pair[0] != pair[1]), Error::<T>::DuplicateSwapGame);
+		for (side, owner) in [(&give, &proposer), (&want, &counterparty)] {
+			for id in side.iter() {
+				let game = Games::<T>::get(id).ok_or(Error::<T>::GameNotFound)?;
+				ensure!(game.owner == *owner, Error::<T>::NotAuthorized);
+				Self::ensure_transferable(&game)?;
+			}
+		}
+
+		let swap_id = NextSwapId::<T>::get();
+		NextSwapId::<T>::put(swap_id.checked_add(1).ok_or(Error::<T>::Overflow)?);
+		for id in give.iter() {
+			SwapsByGame::<T>::try_mutate(id, |swaps| {
+				swaps.try_push(swap_id).map_err(|_| Error::<T>::TooManySwaps)
+			})?;
+		}
+		SwapsExpiringAt::<T>::try_mutate(expiry, |expiring| {
+			expiring.try_push(swap_id).map_err(|_| Error::<T>::TooManySwapsExpiring)
+		})?;
+		let deposit = T::SwapDeposit::get();
+		T::Currency::hold(&HoldReason::SwapDeposit.into(), &proposer, deposit)?;
+		Swaps::<T>::insert(
+			swap_id,
+			Swap {
+				proposer: proposer.clone(),
+				counterparty: counterparty.clone(),
+				give,
+				want,
+				top_up,
+				deposit,
+				expiry,
+			},
+		);
+
+		Self::deposit_event(Event::<T>::SwapProposed { swap_id, proposer, counterparty });
+		Ok(())
+	}
+
+	/// Pays the top-up and moves every game of the swap to its new owner, taking each off sale.
+	pub fn do_accept_swap(counterparty: T::AccountId, swap_id: u32) -> DispatchResult {
+		let swap = Swaps::<T>::get(swap_id).ok_or(Error::<T>::SwapNotFound)?;
+		ensure!(swap.counterparty == counterparty, Error::<T>::NotAuthorized);
+
+		Self::remove_swap(swap_id, &swap);
+		match swap.top_up {
+			Some(TopUp::Give(amount)) => Self::pay(&swap.proposer, &counterparty, amount, None)?,
+			Some(TopUp::Want(amount)) => Self::pay(&counterparty, &swap.proposer, amount, None)?,
+			None => {},
+		}
+		for (games, from, to) in [
+			(&swap.give, &swap.proposer, &counterparty),
+			(&swap.want, &counterparty, &swap.proposer),
+		] {
+			for id in games.iter() {
+				Self::do_transfer(from.clone(), to.clone(), *id)?;
+			}
+		}
+
+		Self::deposit_event(Event::<T>::SwapAccepted { swap_id });
+		Ok(())
+	}
+
+	pub fn do_cancel_swap(proposer: T::AccountId, swap_id: u32) -> DispatchResult {
+		let swap = Swaps::<T>::get(swap_id).ok_or(Error::<T>::SwapNotFound)?;
+		ensure!(swap.proposer == proposer, Error::<T>::NotAuthorized);
+
+		Self::remove_swap(swap_id, &swap);
+		Self::deposit_event(Event::<T>::SwapCancelled { swap_id });
+		Ok(())
+	}
+
+	/// Drops every swap proposal that expires at block `n`.
+	pub fn expire_swaps(n: BlockNumberFor<T>) -> Weight {
+		let expiring = SwapsExpiringAt::<T>::take(n);
+		for swap_id in expiring.iter() {
+			match Swaps::<T>::take(swap_id) {
+				Some(swap) => {
+					Self::unindex_swap_games(*swap_id, &swap);
+					Self::release_swap_deposit(&swap);
+					Self::deposit_event(Event::<T>::SwapExpired { swap_id: *swap_id });
+				},
+				None => {
+					defensive!("every indexed swap is stored; qed");
+				},
+			}
+		}
+		<T as Config>::WeightInfo::expire_swaps(expiring.len() as u32)
+	}
+
+	/// Cancels every swap proposal that offers the game, e.g. because it changed hands.
+	fn cancel_swaps(id: [u8; 32]) {
+		for swap_id in SwapsByGame::<T>::take(id) {
+			match Swaps::<T>::get(swap_id) {
+				Some(swap) => {
+					Self::remove_swap(swap_id, &swap);
+					Self::deposit_event(Event::<T>::SwapCancelled { swap_id });
+				},
+				None => {
+					defensive!("every indexed swap is stored; qed");
+				},
+			}
+		}
+	}
+
+	fn remove_swap(swap_id: u32, swap: &Swap<T>) {
+		Swaps::<T>::remove(swap_id);
+		Self::unindex_swap_games(swap_id, swap);
+		SwapsExpiringAt::<T>::mutate(swap.expiry, |expiring| expiring.retain(|x| *x != swap_id));
+		Self::release_swap_deposit(swap);
+	}
+
+	fn release_swap_deposit(swap: &Swap<T>) {
+		let released = T::Currency::release(
+			&HoldReason::SwapDeposit.into(),
+			&swap.proposer,
+			swap.deposit,
+			Precision::BestEffort,
+		);
+		defensive_assert!(released.is_ok(), "every swap is backed by a hold; qed");
+	}
+
+	fn unindex_swap_games(swap_id: u32, swap: &Swap<T>) {
+		for id in swap.give.iter() {
+			SwapsByGame::<T>::mutate_exists(id, |maybe_swaps| {
+				if let Some(swaps) = maybe_swaps {
+					swaps.retain(|x| *x != swap_id);
+					if swaps.is_empty() {
+						*maybe_swaps = None;
+					}
+				}
+			});
+		}
+	}
+
+	/// Lists `games` together at `price`, taking each of them off sale on its own.
+	pub fn do_create_bundle(
+		seller: T::AccountId,
+		games: BundleGames<T>,
+		price: T::Balance,
+	) -> DispatchResult {
+		ensure!(!games.is_empty(), Error::<T>::EmptyBundle);
+		let mut sorted = games.to_vec();
+		sorted.sort();
+		ensure!(sorted.windows(2).all(|pair| pair[0] != pair[1]), Error::<T>::DuplicateBundleGame);
+		for id in games.iter() {
+			let game = Games::<T>::get(id).ok_or(Error::<T>::GameNotFound)?;
+			ensure!(game.owner == seller, Error::<T>::NotAuthorized);
+			Self::ensure_transferable(&game)?;
+			Self::ensure_unlocked(id)?;
+			Self::ensure_not_rented(&game)?;
+			if game.value.is_some() {
+				Self::do_set_price(seller.clone(), *id, None, None)?;
+			}
+		}
+
+		let bundle_id = NextBundleId::<T>::get();
+		NextBundleId::<T>::put(bundle_id.checked_add(1).ok_or(Error::<T>::Overflow)?);
+		for id in games.iter() {
+			BundledGames::<T>::insert(id, bundle_id);
+		}
+		Bundles::<T>::insert(
+			bundle_id,
+			Bundle { seller: seller.clone(), games: games.clone(), price },
+		);
+
+		Self::deposit_event(Event::<T>::BundleListed { bundle_id, seller, games, price });
+		Ok(())
+	}
+
+	pub fn do_cancel_bundle(seller: T::AccountId, bundle_id: u32) -> DispatchResult {
+		let bundle = Bundles::<T>::get(bundle_id).ok_or(Error::<T>::BundleNotFound)?;
+		ensure!(bundle.seller == seller, Error::<T>::NotAuthorized);
+
+		Self::remove_bundle(bundle_id, &bundle);
+		Self::deposit_event(Event::<T>::BundleCancelled { bundle_id });
+		Ok(())
+	}
+
+	/// Takes a single payment for the bundle and moves every game in it to `buyer`.
+	pub fn do_buy_bundle(
+		buyer: T::AccountId,
+		bundle_id: u32,
+		max_price: T::Balance,
+	) -> DispatchResult {
+		let bundle = Bundles::<T>::get(bundle_id).ok_or(Error::<T>::BundleNotFound)?;
+		ensure!(bundle.price <= max_price, Error::<T>::PriceTooLow);
+		ensure!(bundle.seller != buyer, Error::<T>::TransferToSelf);
+
+		Self::remove_bundle(bundle_id, &bundle);
+		let games = bundle
+			.games
+			.iter()
+			.map(|id| Games::<T>::get(id).map(|game| (*id, game)).ok_or(Error::<T>::GameNotFound))
+			.collect::<Result<Vec<_>, _>>()?;
+		Self::pay_bundle_proceeds(bundle_id, &bundle.seller, &games, &buyer, bundle.price)?;
+		for id in bundle.games.iter() {
+			Self::do_transfer(bundle.seller.clone(), buyer.clone(), *id)?;
+		}
+
+		Self::deposit_event(Event::<T>::BundleSold {
+			bundle_id,
+			buyer,
+			games: bundle.games,
+			price: bundle.price,
+		});
+		Ok(())
+	}
+
+	fn remove_bundle(bundle_id: u32, bundle: &Bundle<T>) {
+		Bundles::<T>::remove(bundle_id);
+		for id in bundle.games.iter() {
+			BundledGames::<T>::remove(id);
+		}
+	}
+
+	/// Pays `price` from `buyer` for a bundle of `games`. The marketplace fee is taken from the
+	/// whole price, and each creator's royalty from an equal share of it.
+	fn pay_bundle_proceeds(
+		bundle_id: u32,
+		seller: &T::AccountId,
+		games: &[([u8; 32], Game<T>)],
+		buyer: &T::AccountId,
+		price: T::Balance,
+	) -> DispatchResult {
+		let share = price / (games.len() as u32).into();
+		let fee = Self::protocol_fee(price);
+		let mut net = price.saturating_sub(fee);
+		let mut royalties = Vec::with_capacity(games.len());
+
+		for (id, game) in games.iter().filter(|(_, game)| game.creator != *seller) {
+			let royalty = Self::receivable(&game.creator, game.royalty.mul_floor(share));
+			net = net.saturating_sub(royalty);
+			Self::pay(buyer, &game.creator, royalty, None)?;
+			royalties.push((*id, royalty));
+		}
+		let (fee, net) = Self::settle_net(seller, buyer, fee, net, None);
+		Self::pay(buyer, &T::FeeDestination::get(), fee, None)?;
+		Self::pay(buyer, seller, net, None)?;
+
+		Self::deposit_event(Event::<T>::BundleProceedsPaid {
+			bundle_id,
+			gross: price,
+			fee,
+			// A bundle holds at most `MaxBundleSize` games, so nothing is truncated.
+			royalties: BoundedVec::truncate_from(royalties),
+			net,
+		});
+		Ok(())
+	}
+
+	/// The bytes a seller signs to authorise `order`. The genesis hash ties the signature to
+	/// this chain, so it cannot be replayed on another one running the same pallet.
+	pub fn order_payload(order: &OrderOf<T>) -> Vec<u8> {
+		let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+		(b"game_order", genesis_hash, order).encode()
+	}
+
+	pub fn order_hash(order: &OrderOf<T>) -> [u8; 32] {
+		BlakeTwo256::hash(&Self::order_payload(order)).into()
+	}
+
+	/// Sells the game to `buyer` on the terms of an order signed by its current owner. Each
+	/// order can be filled at most once, and only while the owner's nonce still matches.
+	pub fn do_fill_order(
+		buyer: T::AccountId,
+		order: OrderOf<T>,
+		signature: T::OffchainSignature,
+	) -> DispatchResult {
+		let game = Games::<T>::get(order.id).ok_or(Error::<T>::GameNotFound)?;
+		let seller = game.owner.clone();
+		ensure!(seller != buyer, Error::<T>::TransferToSelf);
+		Self::ensure_order_open(&order)?;
+		ensure!(order.nonce == OrderNonces::<T>::get(&seller), Error::<T>::StaleOrderNonce);
+		let payload = Self::order_payload(&order);
+		ensure!(signature.verify(&payload[..], &seller), Error::<T>::InvalidOrderSignature);
+		let order_hash: [u8; 32] = BlakeTwo256::hash(&payload).into();
+		ensure!(!ClosedOrders::<T>::contains_key(&seller, order_hash), Error::<T>::OrderClosed);
+
+		Self::close_order(&seller, order_hash, order.expiry, Zero::zero());
+		Self::pay_proceeds(order.id, &game, &buyer, order.price, None)?;
+		Self::do_transfer(seller.clone(), buyer.clone(), order.id)?;
+
+		Self::deposit_event(Event::<T>::OrderFilled {
+			id: order.id,
+			seller,
+			buyer,
+			price: order.price,
+		});
+		Ok(())
+	}
+
+	/// Only the game's owner, who is the only one able to sign a fillable order for it, may
+	/// cancel an order, and each cancellation is backed by a deposit until the order expires.
+	/// Orders too far from expiry to cancel can still be voided with `do_bump_order_nonce`.
+	pub fn do_cancel_order(seller: T::AccountId, order: OrderOf<T>) -> DispatchResult {
+		let game = Games::<T>::get(order.id).ok_or(Error::<T>::GameNotFound)?;
+		ensure!(game.owner == seller, Error::<T>::NotAuthorized);
+		Self::ensure_order_open(&order)?;
+		let order_hash = Self::order_hash(&order);
+		ensure!(!ClosedOrders::<T>::contains_key(&seller, order_hash), Error::<T>::OrderClosed);
+		let deposit = T::OrderCancellationDeposit::get();
+		T::Currency::hold(&HoldReason::OrderCancellation.into(), &seller, deposit)?;
+		Self::close_order(&seller, order_hash, order.expiry, deposit);
+		Self::deposit_event(Event::<T>::OrderCancelled { seller, order_hash });
+		Ok(())
+	}
+
+	/// Checks that the order has not expired.
+	fn ensure_order_open(order: &OrderOf<T>) -> DispatchResult {
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(now < order.expiry, Error::<T>::OrderExpired);
+		Ok(())
+	}
+
+	fn close_order(
+		seller: &T::AccountId,
+		order_hash: [u8; 32],
+		expiry: BlockNumberFor<T>,
+		deposit: T::Balance,
+	) {
+		ClosedOrders::<T>::insert(seller, order_hash, ());
+		ClosedOrdersExpiringAt::<T>::insert(expiry, (seller, order_hash), deposit);
+		// The sweep starts no later than the first block an order was closed in.
+		if !OrderSweepCursor::<T>::exists() {
+			OrderSweepCursor::<T>::put(frame_system::Pallet::<T>::block_number());
+		}
+	}
+
+	/// Forgets closed orders expiring at or before block `now`, which can no longer be filled
+	/// anyway, oldest block first, for as long as `limit` leaves room for another one.
+	pub fn sweep_closed_orders(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+		let mut used = T::DbWeight::get().reads_writes(1, 1);
+		let worst = <T as Config>::WeightInfo::expire_closed_order();
+		if !limit.all_gte(used.saturating_add(worst)) {
+			return Weight::zero();
+		}
+
+		// Without a cursor no order has ever been closed, so there is nothing to prune.
+		let Some(mut cursor) = OrderSweepCursor::<T>::get() else {
+			return T::DbWeight::get().reads(1);
+		};
+		while cursor <= now && limit.all_gte(used.saturating_add(worst)) {
+			if Self::expire_closed_order(cursor) {
+				used.saturating_accrue(worst);
+			} else {
+				used.saturating_accrue(T::DbWeight::get().reads(1));
+				cursor.saturating_inc();
+			}
+		}
+		OrderSweepCursor::<T>::put(cursor);
+		used
+	}
+
+	/// Forgets one closed order expiring at block `n` and refunds its deposit. Returns whether
+	/// there was one.
+	pub fn expire_closed_order(n: BlockNumberFor<T>) -> bool {
+		let Some(((seller, order_hash), deposit)) =
+			ClosedOrdersExpiringAt::<T>::iter_prefix(n).next()
+		else {
+			return false;
+		};
+		ClosedOrdersExpiringAt::<T>::remove(n, (&seller, order_hash));
+		ClosedOrders::<T>::remove(&seller, order_hash);
+		let released = T::Currency::release(
+			&HoldReason::OrderCancellation.into(),
+			&seller,
+			deposit,
+			Precision::BestEffort,
+		);
+		defensive_assert!(released.is_ok(), "every cancelled order is backed by a hold; qed");
+		true
+	}
+
+	pub fn do_bump_order_nonce(seller: T::AccountId) -> DispatchResult {
+		let nonce = OrderNonces::<T>::try_mutate(&seller, |nonce| {
+			*nonce = nonce.checked_add(1).ok_or(Error::<T>::Overflow)?;
+			Ok::<_, Error<T>>(*nonce)
+		})?;
+		Self::deposit_event(Event::<T>::OrderNonceBumped { seller, nonce });
+		Ok(())
+	}
+
+	pub fn do_set_metadata(
+		who: T::AccountId,
+		id: [u8; 32],
+		name: BoundedString<T>,
+		uri: BoundedString<T>,
+		attributes: Attributes<T>,
+	) -> DispatchResult {
+		Self::ensure_metadata_admin(&who, &id)?;
+		let old = GameMetadata::<T>::get(id);
+		ensure!(
+			!old.as_ref().is_some_and(|metadata| metadata.locked),
+			Error::<T>::MetadataIsLocked
+		);
+
+		let bytes = (&name, &uri, &attributes).encoded_size() as u32;
+		let deposit = T::MetadataDepositBase::get()
+			.saturating_add(T::MetadataDepositPerByte::get().saturating_mul(bytes.into()));
+		if let Some(old) = old {
+			Self::release_metadata_deposit(&old);
+		}
+		T::Currency::hold(&HoldReason::MetadataDeposit.into(), &who, deposit)?;
+
+		GameMetadata::<T>::insert(
+			id,
+			Metadata { depositor: who, deposit, name, uri, attributes, locked: false },
+		);
+
+		Self::deposit_event(Event::<T>::MetadataSet { id, deposit });
+		Ok(())
+	}
+
+	pub fn do_clear_metadata(who: T::AccountId, id: [u8; 32]) -> DispatchResult {
+		Self::ensure_metadata_admin(&who, &id)?;
+		let metadata = GameMetadata::<T>::get(id).ok_or(Error::<T>::MetadataNotFound)?;
+		ensure!(!metadata.locked, Error::<T>::MetadataIsLocked);
+
+		Self::remove_metadata(id);
+		Ok(())
+	}
+
+	pub fn do_lock_metadata(who: T::AccountId, id: [u8; 32]) -> DispatchResult {
+		Self::ensure_metadata_admin(&who, &id)?;
+		GameMetadata::<T>::try_mutate(id, |maybe_metadata| {
+			let metadata = maybe_metadata.as_mut().ok_or(Error::<T>::MetadataNotFound)?;
+			metadata.locked = true;
+			Ok::<_, DispatchError>(())
+		})?;
+
+		Self::deposit_event(Event::<T>::MetadataLocked { id });
+		Ok(())
+	}
+
+	/// Removes the game's metadata, locked or not, and refunds its deposit.
+	pub fn remove_metadata(id: [u8; 32]) {
+		if let Some(metadata) = GameMetadata::<T>::take(id) {
+			Self::release_metadata_deposit(&metadata);
+			Self::deposit_event(Event::<T>::MetadataCleared { id });
+		}
+	}
+
+	/// Refunds the metadata deposit `from` paid on a game that is leaving their hands. The
+	/// metadata itself stays, so a sale cannot change what the buyer was shown; it is backed by a
+	/// deposit again once someone replaces it.
+	fn refund_metadata_deposit(from: &T::AccountId, id: [u8; 32]) {
+		let Some(mut metadata) = GameMetadata::<T>::get(id) else { return };
+		if metadata.depositor != *from || metadata.deposit.is_zero() {
+			return;
+		}
+		Self::release_metadata_deposit(&metadata);
+		metadata.deposit = Zero::zero();
+		GameMetadata::<T>::insert(id, metadata);
+	}
+
+	/// Both the owner and the creator of a game may manage its metadata.
+	fn ensure_metadata_admin(who: &T::AccountId, id: &[u8; 32]) -> DispatchResult {
+		let game = Games::<T>::get(id).ok_or(Error::<T>::GameNotFound)?;
+		ensure!(game.owner == *who || game.creator == *who, Error::<T>::NotAuthorized);
+		Ok(())
+	}
+
+	fn release_metadata_deposit(metadata: &Metadata<T>) {
+		let released = T::Currency::release(
+			&HoldReason::MetadataDeposit.into(),
+			&metadata.depositor,
+			metadata.deposit,
+			Precision::BestEffort,
+		);
+		defensive_assert!(released.is_ok(), "metadata is always backed by a deposit; qed");
+	}
+
+	/// Pays `price` from `buyer` to the game's owner, less the marketplace fee and the royalty owed
+	/// to its creator. The funds come from the buyer's hold for `reason`, or from their free
+	/// balance if there is none.
+	///
+	/// A sale never fails because a payee's account does not exist and their share is below the
+	/// existential deposit: such a royalty stays with the seller, and such a net goes to the fee
+	/// account, or back to the buyer if the fee account cannot take it either.
+	fn pay_proceeds(
+		id: [u8; 32],
+		game: &Game<T>,
+		buyer: &T::AccountId,
+		price: T::Balance,
+		reason: Option<HoldReason>,
+	) -> DispatchResult {
+		let royalty = if game.creator == game.owner {
+			Zero::zero()
+		} else {
+			Self::receivable(&game.creator, game.royalty.mul_floor(price))
+		};
+		let fee = Self::protocol_fee(price);
+		let net = price.saturating_sub(royalty).saturating_sub(fee);
+		let (fee, net) = Self::settle_net(&game.owner, buyer, fee, net, reason);
+
+		Self::pay(buyer, &T::FeeDestination::get(), fee, reason)?;
+		Self::pay(buyer, &game.creator, royalty, reason)?;
+		Self::pay(buyer, &game.owner, net, reason)?;
+
+		Self::deposit_event(Event::<T>::ProceedsPaid { id, gross: price, fee, royalty, net });
+		Ok(())
+	}
+
+	/// The highest protocol fee that still leaves room for the largest royalty, so a buyer is
+	/// never charged more than the sale price.
+	pub fn max_protocol_fee() -> Permill {
+		Permill::one().saturating_sub(T::MaxRoyalty::get())
+	}
+
+	/// The marketplace fee on a sale at `price`. A fee too small to be deposited into the fee
+	/// account, because it is below the existential deposit and the account does not exist yet,
+	/// is waived and stays with the seller.
+	fn protocol_fee(price: T::Balance) -> T::Balance {
+		Self::receivable(&T::FeeDestination::get(), ProtocolFee::<T>::get().mul_floor(price))
+	}
+
+	/// `amount` if `who` can be paid it, otherwise nothing.
+	fn receivable(who: &T::AccountId, amount: T::Balance) -> T::Balance {
+		match T::Currency::can_deposit(who, amount, Provenance::Extant) {
+			DepositConsequence::Success => amount,
+			_ => Zero::zero(),
+		}
+	}
+
+	/// Returns the fee and net to pay when the seller is owed `net`. A net the seller cannot
+	/// receive goes to the fee account instead, or, if that cannot take it either, stays with
+	/// the buyer and is released from their hold for `reason`.
+	fn settle_net(
+		seller: &T::AccountId,
+		buyer: &T::AccountId,
+		fee: T::Balance,
+		net: T::Balance,
+		reason: Option<HoldReason>,
+	) -> (T::Balance, T::Balance) {
+		if Self::receivable(seller, net) == net {
+			return (fee, net);
+		}
+		let with_fee = fee.saturating_add(net);
+		if Self::receivable(&T::FeeDestination::get(), with_fee) == with_fee {
+			return (with_fee, Zero::zero());
+		}
+		if let Some(reason) = reason {
+			let released = T::Currency::release(&reason.into(), buyer, net, Precision::BestEffort);
+			defensive_assert!(released.is_ok(), "the whole price is held; qed");
+		}
+		(fee, Zero::zero())
+	}
+
+	fn pay(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: T::Balance,
+		reason: Option<HoldReason>,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(());
+		}
+		match reason {
+			Some(reason) => T::Currency::transfer_on_hold(
+				&reason.into(),
+				from,
+				to,
+				amount,
+				Precision::Exact,
+				Restriction::Free,
+				Fortitude::Polite,
+			),
+			None => T::Currency::transfer(from, to, amount, Preservation::Preserve),
+		}?;
+		Ok(())
+	}
+
+	fn abort_auction(id: [u8; 32]) {
+		let Some(auction) = Auctions::<T>::take(id) else { return };
+		if let Some((bidder, amount)) = auction.highest_bid {
+			let released = T::Currency::release(
+				&HoldReason::AuctionBid.into(),
+				&bidder,
+				amount,
+				Precision::BestEffort,
+			);
+			defensive_assert!(released.is_ok(), "the highest bid is always held; qed");
+		}
+		Self::deposit_event(Event::<T>::AuctionEnded { id });
+	}
+
+	/// Ends a sealed auction without a sale. Unrevealed bids are still settled by the policy and
+	/// the highest bidder gets their deposit back.
+	fn abort_sealed_auction(id: [u8; 32]) {
+		let Some(auction) = SealedAuctions::<T>::take(id) else { return };
+		let highest_bidder = auction.highest_bid.map(|(bidder, _)| bidder);
+		for bid in SealedBids::<T>::take(id) {
+			if !bid.revealed {
+				Self::settle_unrevealed_bid(id, &bid);
+			} else if highest_bidder.as_ref() == Some(&bid.bidder) {
+				let released = T::Currency::release(
+					&HoldReason::SealedBid.into(),
+					&bid.bidder,
+					bid.deposit,
+					Precision::BestEffort,
+				);
+				defensive_assert!(released.is_ok(), "the highest bid is always held; qed");
+			}
+		}
+		Self::deposit_event(Event::<T>::AuctionEnded { id });
+	}
+
+	/// Checks that every game is indexed under its owner and nowhere else, that the mint
+	/// counters agree with the games in storage, that every swap is indexed under its games, and
+	/// that bundled games are indexed under their bundle and still owned by its seller.
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn do_try_state() -> Result<(), TryRuntimeError> {
+		let mut live = 0u32;
+		for (id, game) in Games::<T>::iter() {
+			ensure!(game.key_data == id, "a game is stored under another game's ID");
+			ensure!(
+				GamesOwnedBy::<T>::contains_key(&game.owner, id),
+				"a game is missing from its owner's index"
+			);
+			live.saturating_inc();
+		}
+
+		// Together with the check above, this makes each game appear exactly once in the index.
+		for (owner, id, ()) in GamesOwnedBy::<T>::iter() {
+			let game = Games::<T>::get(id).ok_or("the ownership index lists a missing game")?;
+			ensure!(game.owner == owner, "the ownership index lists a game under a former owner");
+		}
+
+		ensure!(LiveGameCount::<T>::get() == live, "LiveGameCount differs from the stored games");
+		ensure!(GameCount::<T>::get() >= live, "more games are stored than were ever minted");
+
+		for (swap_id, swap) in Swaps::<T>::iter() {
+			for id in swap.give.iter() {
+				ensure!(
+					SwapsByGame::<T>::get(id).contains(&swap_id),
+					"a swap is missing from the index of one of the games it offers"
+				);
+			}
+		}
+
+		for (bundle_id, bundle) in Bundles::<T>::iter() {
+			for id in bundle.games.iter() {
+				ensure!(
+					BundledGames::<T>::get(id) == Some(bundle_id),
+					"a bundled game is not indexed under its bundle"
+				);
+				let game = Games::<T>::get(id).ok_or("a bundle lists a missing game")?;
+				ensure!(game.owner == bundle.seller, "a bundled game changed owner");
+			}
+		}
+		for (id, bundle_id) in BundledGames::<T>::iter() {
+			let bundle =
+				Bundles::<T>::get(bundle_id).ok_or("a game is indexed under a missing bundle")?;
+			ensure!(bundle.games.contains(&id), "a game is indexed under a bundle without it");
+		}
+		Ok(())
+	}
 }
@@ -1,32 +1,198 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+use alloc::vec::Vec;
+use frame::arithmetic::Permill;
+use frame::deps::sp_runtime::traits::{IdentifyAccount, Verify};
 use frame::prelude::*;
-use frame::primitives::BlakeTwo256;
-use frame::traits::Hash;
+use frame::traits::{fungible, Randomness};
 pub use pallet::*;
 
 #[cfg(test)]
 mod mock;
 
 mod impls;
+pub mod migrations;
 mod tests;
+mod types;
+pub use types::{GameInfo, Listings, Supply};
+
+/// Creates the keys and signatures that the benchmarks of signed orders need.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<Public, AccountId, Signature> {
+	/// A fresh key, along with the account it controls.
+	fn signer() -> (Public, AccountId);
+	fn sign(signer: &Public, message: &[u8]) -> Signature;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmark_helper {
+	use super::BenchmarkHelper;
+	use frame::deps::sp_io::crypto::{sr25519_generate, sr25519_sign};
+	use frame::deps::sp_runtime::{
+		traits::IdentifyAccount, AccountId32, KeyTypeId, MultiSignature, MultiSigner,
+	};
+
+	const ORDER_KEY_TYPE: KeyTypeId = KeyTypeId(*b"gord");
+
+	impl BenchmarkHelper<MultiSigner, AccountId32, MultiSignature> for () {
+		fn signer() -> (MultiSigner, AccountId32) {
+			let signer = MultiSigner::Sr25519(sr25519_generate(ORDER_KEY_TYPE, None));
+			(signer.clone(), signer.into_account())
+		}
+
+		fn sign(signer: &MultiSigner, message: &[u8]) -> MultiSignature {
+			let MultiSigner::Sr25519(public) = signer else {
+				panic!("benchmark signers are sr25519 keys");
+			};
+			sr25519_sign(ORDER_KEY_TYPE, public, message)
+				.expect("the key was generated in the keystore")
+				.into()
+		}
+	}
+}
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 pub mod weights;
 pub use weights::*;
 
-#[frame::pallet(dev_mode)]
+#[frame::pallet]
 pub mod pallet {
 	use super::*;
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(core::marker::PhantomData<T>);
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config + pallet_balances::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		type WeightInfo: WeightInfo;
+		/// The currency used to pay for games and to hold bids.
+		type Currency: fungible::Mutate<Self::AccountId, Balance = Self::Balance>
+			+ fungible::MutateHold<Self::AccountId, Reason = <Self as Config>::RuntimeHoldReason>;
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
+		/// Source of the randomness that new game IDs are derived from.
+		type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+		/// Maximum number of auctions that can end in the same block.
+		#[pallet::constant]
+		type MaxAuctionsPerBlock: Get<u32>;
+		/// Maximum weight `on_initialize` spends settling ended auctions, sealed or not, in one
+		/// block. Auctions that do not fit are settled in the following blocks.
+		#[pallet::constant]
+		type MaxSettlementWeight: Get<Weight>;
+		/// Maximum number of sealed bids committed to a single auction.
+		#[pallet::constant]
+		type MaxSealedBids: Get<u32>;
+		/// What happens to the deposit behind a sealed bid that is never revealed.
+		#[pallet::constant]
+		type UnrevealedBidPolicy: Get<UnrevealedBidPolicy>;
+		/// Maximum number of open offers on a single game. Once a game has this many, a new
+		/// offer must beat the lowest one, which is released to make way for it.
+		#[pallet::constant]
+		type MaxOffersPerGame: Get<u32>;
+		/// Maximum number of offers that can expire in the same block.
+		#[pallet::constant]
+		type MaxOffersPerBlock: Get<u32>;
+		/// Smallest amount an offer may hold. Must be at least the existential deposit.
+		#[pallet::constant]
+		type MinOffer: Get<Self::Balance>;
+		/// Longest an offer may stay open, in blocks.
+		#[pallet::constant]
+		type MaxOfferDuration: Get<BlockNumberFor<Self>>;
+		/// Maximum number of listings that can expire in the same block.
+		#[pallet::constant]
+		type MaxListingsPerBlock: Get<u32>;
+		/// Most games a single `listings` query or `listings_from` page reads from storage.
+		#[pallet::constant]
+		type MaxListingsScanned: Get<u32>;
+		/// Maximum number of games on each side of a swap.
+		#[pallet::constant]
+		type MaxSwapGames: Get<u32>;
+		/// Maximum number of open swap proposals that offer a single game.
+		#[pallet::constant]
+		type MaxSwapsPerGame: Get<u32>;
+		/// Maximum number of swap proposals that can expire in the same block.
+		#[pallet::constant]
+		type MaxSwapsPerBlock: Get<u32>;
+		/// Deposit held from the proposer of a swap until it is accepted, cancelled or expires.
+		#[pallet::constant]
+		type SwapDeposit: Get<Self::Balance>;
+		/// Longest a swap proposal may stay open, in blocks.
+		#[pallet::constant]
+		type MaxSwapDuration: Get<BlockNumberFor<Self>>;
+		/// Maximum number of games sold together as one bundle.
+		#[pallet::constant]
+		type MaxBundleSize: Get<u32>;
+		/// Maximum number of games created or transferred by a single batch call.
+		#[pallet::constant]
+		type MaxBatchSize: Get<u32>;
+		/// Highest royalty a creator can set on their games.
+		#[pallet::constant]
+		type MaxRoyalty: Get<Permill>;
+		/// Marketplace fee charged on every sale until root sets another rate. Together with
+		/// `MaxRoyalty` it must not exceed 100%.
+		#[pallet::constant]
+		type DefaultProtocolFee: Get<Permill>;
+		/// Account that receives the marketplace fee.
+		type FeeDestination: Get<Self::AccountId>;
+		/// Maximum length of a game's name, URI and of each attribute key and value.
+		#[pallet::constant]
+		type StringLimit: Get<u32>;
+		/// Maximum number of attributes in a game's metadata.
+		#[pallet::constant]
+		type MaxAttributes: Get<u32>;
+		/// Deposit held for storing metadata, whatever its size.
+		#[pallet::constant]
+		type MetadataDepositBase: Get<Self::Balance>;
+		/// Additional deposit held per byte of metadata.
+		#[pallet::constant]
+		type MetadataDepositPerByte: Get<Self::Balance>;
+		/// Origin allowed to issue soulbound games, such as badges and achievements.
+		type IssuerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Signature that sellers put on off-chain orders.
+		type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+		/// Key that checks an `OffchainSignature` and identifies the seller's account.
+		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+		/// Deposit held from a seller for each order they cancel, until the order expires.
+		#[pallet::constant]
+		type OrderCancellationDeposit: Get<Self::Balance>;
+		/// Signs orders for the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<
+			Self::OffchainPublic,
+			Self::AccountId,
+			Self::OffchainSignature,
+		>;
+	}
+
+	/// A reason for the pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// The funds back the highest bid of a running auction.
+		#[codec(index = 0)]
+		AuctionBid,
+		/// The funds back an open offer on a game.
+		#[codec(index = 1)]
+		Offer,
+		/// The funds cover the storage taken by a game's metadata.
+		#[codec(index = 2)]
+		MetadataDeposit,
+		/// The funds back a sealed bid until its auction settles.
+		#[codec(index = 3)]
+		SealedBid,
+		/// The funds back an open swap proposal.
+		#[codec(index = 4)]
+		SwapDeposit,
+		/// The funds back a cancelled order until it expires.
+		#[codec(index = 5)]
+		OrderCancellation,
 	}
 
 	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen)]
@@ -34,33 +200,558 @@ pub mod pallet {
 	pub struct Game<T: Config> {
 		pub key_data: [u8; 32],
 		pub owner: T::AccountId,
+		pub creator: T::AccountId,
 		pub value: Option<T::Balance>,
+		/// Share of every sale paid to `creator` when someone else sells the game.
+		pub royalty: Permill,
+		/// The latest rental, which is over once its `until` block is reached.
+		pub rental: Option<Rental<T>>,
+		/// Soulbound games can be burned but never change owner.
+		pub transferable: bool,
+	}
+
+	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Rental<T: Config> {
+		pub renter: T::AccountId,
+		pub until: BlockNumberFor<T>,
+	}
+
+	/// A rental the owner has offered and the renter has yet to pay for.
+	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct RentalTerms<T: Config> {
+		pub renter: T::AccountId,
+		pub until: BlockNumberFor<T>,
+		pub fee: T::Balance,
+	}
+
+	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Auction<T: Config> {
+		pub seller: T::AccountId,
+		pub reserve: T::Balance,
+		pub end: BlockNumberFor<T>,
+		pub highest_bid: Option<(T::AccountId, T::Balance)>,
+	}
+
+	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct DutchAuction<T: Config> {
+		pub seller: T::AccountId,
+		pub start_price: T::Balance,
+		pub floor_price: T::Balance,
+		pub start: BlockNumberFor<T>,
+		/// Number of blocks over which the price falls from `start_price` to `floor_price`.
+		pub duration: BlockNumberFor<T>,
+	}
+
+	/// An auction whose bids stay hidden until they are revealed. Bids are committed before
+	/// `commit_end` and revealed before `reveal_end`, when the auction settles.
+	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct SealedAuction<T: Config> {
+		pub seller: T::AccountId,
+		pub reserve: T::Balance,
+		pub commit_end: BlockNumberFor<T>,
+		pub reveal_end: BlockNumberFor<T>,
+		pub highest_bid: Option<(T::AccountId, T::Balance)>,
+	}
+
+	/// A hidden bid: the hash of the bid and a salt, backed by a held deposit that must cover the
+	/// bid once it is revealed.
+	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct SealedBid<T: Config> {
+		pub bidder: T::AccountId,
+		pub commitment: [u8; 32],
+		pub deposit: T::Balance,
+		pub revealed: bool,
+	}
+
+	/// What happens to the deposit behind a sealed bid that is never revealed.
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum UnrevealedBidPolicy {
+		/// The deposit is returned to the bidder.
+		Refund,
+		/// The deposit is burned.
+		Slash,
+	}
+
+	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Offer<T: Config> {
+		pub buyer: T::AccountId,
+		pub amount: T::Balance,
+		pub expiry: BlockNumberFor<T>,
+	}
+
+	/// Funds paid on top of the games when a swap goes through.
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum TopUp<Balance> {
+		/// The proposer pays the counterparty.
+		Give(Balance),
+		/// The counterparty pays the proposer.
+		Want(Balance),
+	}
+
+	pub type SwapGames<T> = BoundedVec<[u8; 32], <T as Config>::MaxSwapGames>;
+
+	/// A proposal to trade the proposer's `give` games for the counterparty's `want` games.
+	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Swap<T: Config> {
+		pub proposer: T::AccountId,
+		pub counterparty: T::AccountId,
+		pub give: SwapGames<T>,
+		pub want: SwapGames<T>,
+		pub top_up: Option<TopUp<T::Balance>>,
+		/// Held from the proposer while the proposal is open.
+		pub deposit: T::Balance,
+		pub expiry: BlockNumberFor<T>,
+	}
+
+	pub type BundleGames<T> = BoundedVec<[u8; 32], <T as Config>::MaxBundleSize>;
+
+	/// Games listed together for a single price. They stay locked until the bundle is sold or
+	/// cancelled.
+	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Bundle<T: Config> {
+		pub seller: T::AccountId,
+		pub games: BundleGames<T>,
+		pub price: T::Balance,
+	}
+
+	/// A sale the game's owner signs off-chain. Anyone holding it can buy the game at `price`
+	/// with `fill_order` before block `expiry`, as long as the owner's order nonce is still
+	/// `nonce`.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Order<Balance, BlockNumber> {
+		pub id: [u8; 32],
+		pub price: Balance,
+		pub expiry: BlockNumber,
+		pub nonce: u32,
+	}
+
+	pub type OrderOf<T> = Order<<T as pallet_balances::Config>::Balance, BlockNumberFor<T>>;
+
+	pub type BoundedString<T> = BoundedVec<u8, <T as Config>::StringLimit>;
+	pub type Attributes<T> =
+		BoundedVec<(BoundedString<T>, BoundedString<T>), <T as Config>::MaxAttributes>;
+
+	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Metadata<T: Config> {
+		pub depositor: T::AccountId,
+		pub deposit: T::Balance,
+		pub name: BoundedString<T>,
+		pub uri: BoundedString<T>,
+		pub attributes: Attributes<T>,
+		/// Locked metadata can no longer be changed or cleared.
+		pub locked: bool,
 	}
 
 	#[pallet::storage]
 	pub type GlobalValue<T> = StorageValue<_, u32>;
 
+	/// Number of games ever minted, burned ones included.
 	#[pallet::storage]
 	pub(super) type GameCount<T: Config> = StorageValue<Value = u32, QueryKind = ValueQuery>;
 
+	/// Number of games that currently exist.
+	#[pallet::storage]
+	pub(super) type LiveGameCount<T: Config> = StorageValue<Value = u32, QueryKind = ValueQuery>;
+
+	#[pallet::storage]
+	pub(super) type Games<T: Config> =
+		StorageMap<Hasher = Blake2_128Concat, Key = [u8; 32], Value = Game<T>>;
+
+	/// Index of the games each account owns.
+	#[pallet::storage]
+	pub(super) type GamesOwnedBy<T: Config> = StorageDoubleMap<
+		Hasher1 = Blake2_128Concat,
+		Key1 = T::AccountId,
+		Hasher2 = Blake2_128Concat,
+		Key2 = [u8; 32],
+		Value = (),
+	>;
+
+	#[pallet::storage]
+	pub(super) type Approvals<T: Config> =
+		StorageMap<Hasher = Blake2_128Concat, Key = [u8; 32], Value = T::AccountId>;
+
+	#[pallet::storage]
+	pub(super) type Operators<T: Config> = StorageDoubleMap<
+		Hasher1 = Blake2_128Concat,
+		Key1 = T::AccountId,
+		Hasher2 = Blake2_128Concat,
+		Key2 = T::AccountId,
+		Value = (),
+	>;
+
+	#[pallet::storage]
+	pub(super) type Auctions<T: Config> =
+		StorageMap<Hasher = Blake2_128Concat, Key = [u8; 32], Value = Auction<T>>;
+
+	#[pallet::storage]
+	pub(super) type AuctionsEndingAt<T: Config> = StorageMap<
+		Hasher = Twox64Concat,
+		Key = BlockNumberFor<T>,
+		Value = BoundedVec<[u8; 32], T::MaxAuctionsPerBlock>,
+		QueryKind = ValueQuery,
+	>;
+
 	#[pallet::storage]
-	pub(super) type Games<T: Config> = StorageMap<Key = [u8; 32], Value = Game<T>>;
+	pub(super) type DutchAuctions<T: Config> =
+		StorageMap<Hasher = Blake2_128Concat, Key = [u8; 32], Value = DutchAuction<T>>;
 
 	#[pallet::storage]
-	pub(super) type GamesOwnedBy<T: Config> = StorageMap<
+	pub(super) type SealedAuctions<T: Config> =
+		StorageMap<Hasher = Blake2_128Concat, Key = [u8; 32], Value = SealedAuction<T>>;
+
+	/// Sealed auctions indexed by the block in which their reveal phase ends.
+	#[pallet::storage]
+	pub(super) type SealedAuctionsEndingAt<T: Config> = StorageMap<
+		Hasher = Twox64Concat,
+		Key = BlockNumberFor<T>,
+		Value = BoundedVec<[u8; 32], T::MaxAuctionsPerBlock>,
+		QueryKind = ValueQuery,
+	>;
+
+	#[pallet::storage]
+	pub(super) type SealedBids<T: Config> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = [u8; 32],
+		Value = BoundedVec<SealedBid<T>, T::MaxSealedBids>,
+		QueryKind = ValueQuery,
+	>;
+
+	#[pallet::storage]
+	pub(super) type ProtocolFee<T: Config> =
+		StorageValue<Value = Permill, QueryKind = ValueQuery, OnEmpty = T::DefaultProtocolFee>;
+
+	#[pallet::storage]
+	pub(super) type GameMetadata<T: Config> =
+		StorageMap<Hasher = Blake2_128Concat, Key = [u8; 32], Value = Metadata<T>>;
+
+	#[pallet::storage]
+	pub(super) type Offers<T: Config> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = [u8; 32],
+		Value = BoundedVec<Offer<T>, T::MaxOffersPerGame>,
+		QueryKind = ValueQuery,
+	>;
+
+	#[pallet::storage]
+	pub(super) type OffersExpiringAt<T: Config> = StorageMap<
+		Hasher = Twox64Concat,
+		Key = BlockNumberFor<T>,
+		Value = BoundedVec<([u8; 32], T::AccountId), T::MaxOffersPerBlock>,
+		QueryKind = ValueQuery,
+	>;
+
+	/// Block at which a fixed-price listing stops being buyable.
+	#[pallet::storage]
+	pub(super) type ListingExpiry<T: Config> =
+		StorageMap<Hasher = Blake2_128Concat, Key = [u8; 32], Value = BlockNumberFor<T>>;
+
+	#[pallet::storage]
+	pub(super) type ListingsExpiringAt<T: Config> = StorageMap<
+		Hasher = Twox64Concat,
+		Key = BlockNumberFor<T>,
+		Value = BoundedVec<[u8; 32], T::MaxListingsPerBlock>,
+		QueryKind = ValueQuery,
+	>;
+
+	#[pallet::storage]
+	pub(super) type RentalOffers<T: Config> =
+		StorageMap<Hasher = Blake2_128Concat, Key = [u8; 32], Value = RentalTerms<T>>;
+
+	/// ID the next swap proposal will get.
+	#[pallet::storage]
+	pub(super) type NextSwapId<T: Config> = StorageValue<Value = u32, QueryKind = ValueQuery>;
+
+	#[pallet::storage]
+	pub(super) type Swaps<T: Config> =
+		StorageMap<Hasher = Twox64Concat, Key = u32, Value = Swap<T>>;
+
+	/// Open swap proposals indexed by each game they offer, so that they can be cancelled when
+	/// one of those games changes owner. Games a proposal only wants are not indexed, so others
+	/// cannot use up their slots; such a proposal fails on acceptance once the wanted game has
+	/// moved and is released at its expiry.
+	#[pallet::storage]
+	pub(super) type SwapsByGame<T: Config> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = [u8; 32],
+		Value = BoundedVec<u32, T::MaxSwapsPerGame>,
+		QueryKind = ValueQuery,
+	>;
+
+	#[pallet::storage]
+	pub(super) type SwapsExpiringAt<T: Config> = StorageMap<
+		Hasher = Twox64Concat,
+		Key = BlockNumberFor<T>,
+		Value = BoundedVec<u32, T::MaxSwapsPerBlock>,
+		QueryKind = ValueQuery,
+	>;
+
+	/// ID the next bundle will get.
+	#[pallet::storage]
+	pub(super) type NextBundleId<T: Config> = StorageValue<Value = u32, QueryKind = ValueQuery>;
+
+	#[pallet::storage]
+	pub(super) type Bundles<T: Config> =
+		StorageMap<Hasher = Twox64Concat, Key = u32, Value = Bundle<T>>;
+
+	/// The bundle each bundled game belongs to.
+	#[pallet::storage]
+	pub(super) type BundledGames<T: Config> =
+		StorageMap<Hasher = Blake2_128Concat, Key = [u8; 32], Value = u32>;
+
+	/// Each account's order nonce. Orders signed with any other nonce cannot be filled.
+	#[pallet::storage]
+	pub(super) type OrderNonces<T: Config> = StorageMap<
+		Hasher = Blake2_128Concat,
 		Key = T::AccountId,
-		Value = BoundedVec<[u8; 32], ConstU32<100>>,
+		Value = u32,
 		QueryKind = ValueQuery,
 	>;
 
+	/// Hashes of the orders each seller has had filled or has cancelled. Each is pruned once the
+	/// order expires, since it can no longer be filled then.
+	#[pallet::storage]
+	pub(super) type ClosedOrders<T: Config> = StorageDoubleMap<
+		Hasher1 = Blake2_128Concat,
+		Key1 = T::AccountId,
+		Hasher2 = Identity,
+		Key2 = [u8; 32],
+		Value = (),
+	>;
+
+	/// Closed orders indexed by the block they expire at, with the deposit held for each. There
+	/// is no limit per block, so that no account can crowd out the orders of others.
+	#[pallet::storage]
+	pub(super) type ClosedOrdersExpiringAt<T: Config> = StorageDoubleMap<
+		Hasher1 = Twox64Concat,
+		Key1 = BlockNumberFor<T>,
+		Hasher2 = Blake2_128Concat,
+		Key2 = (T::AccountId, [u8; 32]),
+		Value = T::Balance,
+	>;
+
+	/// Next block whose expired closed orders `on_idle` will prune.
+	#[pallet::storage]
+	pub(super) type OrderSweepCursor<T: Config> = StorageValue<Value = BlockNumberFor<T>>;
+
+	/// Oldest block whose ended auctions have not all been settled yet.
+	#[pallet::storage]
+	pub(super) type AuctionSettlementCursor<T: Config> = StorageValue<Value = BlockNumberFor<T>>;
+
+	/// Oldest block whose ended sealed auctions have not all been settled yet.
+	#[pallet::storage]
+	pub(super) type SealedAuctionSettlementCursor<T: Config> =
+		StorageValue<Value = BlockNumberFor<T>>;
+
+	/// Next block whose expired listings `on_idle` will sweep.
+	#[pallet::storage]
+	pub(super) type ListingSweepCursor<T: Config> = StorageValue<Value = BlockNumberFor<T>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		ValueUpdated { value: u32, account: T::AccountId },
-		GameCreated { owner: T::AccountId, id: [u8; 32] },
-		GameTransferred { from: T::AccountId, to: T::AccountId, id: [u8; 32] },
-		PriceUpdated { owner: T::AccountId, id: [u8; 32], price: Option<T::Balance> },
-		GameSold { buyer: T::AccountId, id: [u8; 32], price: T::Balance },
+		ValueUpdated {
+			value: u32,
+			account: T::AccountId,
+		},
+		GameCreated {
+			owner: T::AccountId,
+			id: [u8; 32],
+		},
+		GameTransferred {
+			from: T::AccountId,
+			to: T::AccountId,
+			id: [u8; 32],
+		},
+		PriceUpdated {
+			owner: T::AccountId,
+			id: [u8; 32],
+			price: Option<T::Balance>,
+			expiry: Option<BlockNumberFor<T>>,
+		},
+		GameSold {
+			buyer: T::AccountId,
+			id: [u8; 32],
+			price: T::Balance,
+		},
+		AuctionStarted {
+			id: [u8; 32],
+			reserve: T::Balance,
+			end: BlockNumberFor<T>,
+		},
+		BidPlaced {
+			bidder: T::AccountId,
+			id: [u8; 32],
+			amount: T::Balance,
+		},
+		AuctionCancelled {
+			id: [u8; 32],
+		},
+		AuctionSettled {
+			winner: T::AccountId,
+			id: [u8; 32],
+			price: T::Balance,
+		},
+		AuctionEnded {
+			id: [u8; 32],
+		},
+		DutchAuctionStarted {
+			id: [u8; 32],
+			start_price: T::Balance,
+			floor_price: T::Balance,
+		},
+		OfferMade {
+			buyer: T::AccountId,
+			id: [u8; 32],
+			amount: T::Balance,
+		},
+		OfferAccepted {
+			buyer: T::AccountId,
+			id: [u8; 32],
+			amount: T::Balance,
+		},
+		OfferReleased {
+			buyer: T::AccountId,
+			id: [u8; 32],
+		},
+		ProceedsPaid {
+			id: [u8; 32],
+			gross: T::Balance,
+			fee: T::Balance,
+			royalty: T::Balance,
+			net: T::Balance,
+		},
+		ProtocolFeeSet {
+			fee: Permill,
+		},
+		MetadataSet {
+			id: [u8; 32],
+			deposit: T::Balance,
+		},
+		MetadataCleared {
+			id: [u8; 32],
+		},
+		MetadataLocked {
+			id: [u8; 32],
+		},
+		Approval {
+			owner: T::AccountId,
+			delegate: Option<T::AccountId>,
+			id: [u8; 32],
+		},
+		OperatorSet {
+			owner: T::AccountId,
+			operator: T::AccountId,
+			approved: bool,
+		},
+		GameBurned {
+			owner: T::AccountId,
+			id: [u8; 32],
+		},
+		ListingExpired {
+			id: [u8; 32],
+		},
+		RentalOffered {
+			id: [u8; 32],
+			renter: T::AccountId,
+			until: BlockNumberFor<T>,
+			fee: T::Balance,
+		},
+		RentalStarted {
+			id: [u8; 32],
+			renter: T::AccountId,
+			until: BlockNumberFor<T>,
+		},
+		GamesCreated {
+			owner: T::AccountId,
+			count: u32,
+		},
+		GamesTransferred {
+			from: T::AccountId,
+			count: u32,
+		},
+		SealedAuctionStarted {
+			id: [u8; 32],
+			reserve: T::Balance,
+			commit_end: BlockNumberFor<T>,
+			reveal_end: BlockNumberFor<T>,
+		},
+		BidCommitted {
+			bidder: T::AccountId,
+			id: [u8; 32],
+			deposit: T::Balance,
+		},
+		BidRevealed {
+			bidder: T::AccountId,
+			id: [u8; 32],
+			amount: T::Balance,
+		},
+		UnrevealedBidSettled {
+			bidder: T::AccountId,
+			id: [u8; 32],
+			deposit: T::Balance,
+			slashed: bool,
+		},
+		SwapProposed {
+			swap_id: u32,
+			proposer: T::AccountId,
+			counterparty: T::AccountId,
+		},
+		SwapAccepted {
+			swap_id: u32,
+		},
+		SwapCancelled {
+			swap_id: u32,
+		},
+		SwapExpired {
+			swap_id: u32,
+		},
+		BundleListed {
+			bundle_id: u32,
+			seller: T::AccountId,
+			games: BundleGames<T>,
+			price: T::Balance,
+		},
+		BundleCancelled {
+			bundle_id: u32,
+		},
+		BundleSold {
+			bundle_id: u32,
+			buyer: T::AccountId,
+			games: BundleGames<T>,
+			price: T::Balance,
+		},
+		OrderFilled {
+			id: [u8; 32],
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			price: T::Balance,
+		},
+		OrderCancelled {
+			seller: T::AccountId,
+			order_hash: [u8; 32],
+		},
+		OrderNonceBumped {
+			seller: T::AccountId,
+			nonce: u32,
+		},
+		BundleProceedsPaid {
+			bundle_id: u32,
+			gross: T::Balance,
+			fee: T::Balance,
+			royalties: BoundedVec<([u8; 32], T::Balance), T::MaxBundleSize>,
+			net: T::Balance,
+		},
 	}
 
 	#[pallet::error]
@@ -70,144 +761,556 @@ pub mod pallet {
 		TooManyGames,
 		DuplicatedGame,
 		GameNotFound,
+		/// Deprecated: no longer returned, as accounts may own any number of games. Kept so the
+		/// errors after it keep their index.
 		TooManyGamesOwned,
 		NotAuthorized,
 		TransferToSelf,
 		NotForSale,
 		PriceTooLow,
+		GameLocked,
+		AuctionNotFound,
+		AuctionEndInPast,
+		AuctionClosed,
+		TooManyAuctionsEnding,
+		BidTooLow,
+		AuctionHasBids,
+		InvalidPriceSchedule,
+		OfferNotFound,
+		OfferExists,
+		OfferExpiryInPast,
+		TooManyOffers,
+		TooManyOffersExpiring,
+		RoyaltyTooHigh,
+		MetadataNotFound,
+		MetadataIsLocked,
+		ListingExpiryInPast,
+		TooManyListingsExpiring,
+		GameRented,
+		RentalNotFound,
+		RentalEndInPast,
+		EmptyBatch,
+		BatchTooLarge,
+		InvalidAuctionPhases,
+		BidAlreadyCommitted,
+		TooManySealedBids,
+		NotRevealing,
+		BidNotFound,
+		BidAlreadyRevealed,
+		CommitmentMismatch,
+		BidExceedsDeposit,
+		SwapNotFound,
+		EmptySwap,
+		DuplicateSwapGame,
+		SwapExpiryInPast,
+		TooManySwaps,
+		TooManySwapsExpiring,
+		BundleNotFound,
+		EmptyBundle,
+		DuplicateBundleGame,
+		OrderExpired,
+		StaleOrderNonce,
+		InvalidOrderSignature,
+		OrderClosed,
+		Soulbound,
+		FeeTooHigh,
+		SwapExpiryTooFar,
+		OfferTooLow,
+		OfferExpiryTooFar,
+	}
+
+	/// Name, URI and attributes of a game minted at genesis.
+	pub type GenesisMetadata = (Vec<u8>, Vec<u8>, Vec<(Vec<u8>, Vec<u8>)>);
+	/// Owner, ID, optional price and optional metadata of a game minted at genesis.
+	pub type GenesisGame<T> = (
+		<T as frame_system::Config>::AccountId,
+		[u8; 32],
+		Option<<T as pallet_balances::Config>::Balance>,
+		Option<GenesisMetadata>,
+	);
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Games that exist from the first block. Each owner is also the game's creator and pays
+		/// its metadata deposit.
+		pub games: Vec<GenesisGame<T>>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (owner, id, price, metadata) in &self.games {
+				Pallet::<T>::build_genesis_game(owner.clone(), *id, *price, metadata.clone());
+			}
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let limit = T::MaxSettlementWeight::get();
+			let settled = Self::settle_auctions(n, limit);
+			settled
+				.saturating_add(Self::settle_sealed_auctions(n, limit.saturating_sub(settled)))
+				.saturating_add(Self::expire_offers(n))
+				.saturating_add(Self::expire_swaps(n))
+		}
+
+		fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let used = Self::sweep_listings(n, remaining_weight);
+			used.saturating_add(Self::sweep_closed_orders(n, remaining_weight.saturating_sub(used)))
+		}
+
+		fn integrity_test() {
+			assert!(
+				T::DefaultProtocolFee::get() <= Self::max_protocol_fee(),
+				"the default protocol fee and the largest royalty must not exceed the sale price"
+			);
+			assert!(
+				T::MinOffer::get() >= T::ExistentialDeposit::get(),
+				"offers below the existential deposit would let anyone fill a game's offers for free"
+			);
+			let settlement = T::MaxSettlementWeight::get();
+			assert!(
+				settlement.all_lte(<T as frame_system::Config>::BlockWeights::get().max_block),
+				"auction settlement must fit in a block"
+			);
+			assert!(
+				settlement.all_gte(
+					T::DbWeight::get().reads_writes(1, 1).saturating_add(
+						<T as Config>::WeightInfo::settle_auction()
+							.max(<T as Config>::WeightInfo::settle_sealed_auction())
+					)
+				),
+				"a settlement budget below one auction would never settle any"
+			);
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(
+			_n: BlockNumberFor<T>,
+		) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::do_something())]
-		pub fn create_game(origin: OriginFor<T>) -> DispatchResult {
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_game())]
+		pub fn create_game(origin: OriginFor<T>, royalty: Permill) -> DispatchResult {
 			let caller = ensure_signed(origin)?;
-			let key_data = Self::generate_key_data();
-			Self::mint_new_game(caller, key_data)?;
+			let key_data = Self::gen_game_key();
+			Self::mint(caller, key_data, royalty, true)?;
 			Ok(())
 		}
 
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::do_something())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::transfer_game())]
 		pub fn transfer_game(
 			origin: OriginFor<T>,
 			to: T::AccountId,
 			id: [u8; 32],
 		) -> DispatchResult {
 			let from = ensure_signed(origin)?;
-			Self::perform_transfer(from, to, id)?;
+			Self::do_transfer(from, to, id)?;
 			Ok(())
 		}
 
 		#[pallet::call_index(2)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::do_something())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_game_price())]
 		pub fn set_game_price(
 			origin: OriginFor<T>,
 			id: [u8; 32],
 			price: Option<T::Balance>,
+			expiry: Option<BlockNumberFor<T>>,
 		) -> DispatchResult {
 			let owner = ensure_signed(origin)?;
-			Self::update_price(owner, id, price)?;
+			Self::do_set_price(owner, id, price, expiry)?;
 			Ok(())
 		}
 
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::do_something())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::buy_game())]
 		pub fn buy_game(
 			origin: OriginFor<T>,
 			id: [u8; 32],
 			max_price: T::Balance,
 		) -> DispatchResult {
 			let buyer = ensure_signed(origin)?;
-			Self::purchase_game(buyer, id, max_price)?;
+			Self::do_buy_game(buyer, id, max_price)?;
 			Ok(())
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
-		fn generate_key_data() -> [u8; 32] {
-			let unique_payload = (
-				frame_system::Pallet::<T>::parent_hash(),
-				frame_system::Pallet::<T>::block_number(),
-				frame_system::Pallet::<T>::extrinsic_index(),
-				GameCount::<T>::get(),
-			);
-			let hash: [u8; 32] = BlakeTwo256::hash_of(&unique_payload).into();
-			hash
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_auction())]
+		pub fn create_auction(
+			origin: OriginFor<T>,
+			id: [u8; 32],
+			reserve: T::Balance,
+			end: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			Self::do_create_auction(seller, id, reserve, end)?;
+			Ok(())
 		}
 
-		fn mint_new_game(owner: T::AccountId, key_data: [u8; 32]) -> DispatchResult {
-			let current_count = GameCount::<T>::get();
-			let new_count = current_count.checked_add(1).ok_or(Error::<T>::TooManyGames)?;
-			GameCount::<T>::set(new_count);
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::bid())]
+		pub fn bid(origin: OriginFor<T>, id: [u8; 32], amount: T::Balance) -> DispatchResult {
+			let bidder = ensure_signed(origin)?;
+			Self::do_bid(bidder, id, amount)?;
+			Ok(())
+		}
 
-			ensure!(!Games::<T>::contains_key(key_data), Error::<T>::DuplicatedGame);
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_auction())]
+		pub fn cancel_auction(origin: OriginFor<T>, id: [u8; 32]) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			Self::do_cancel_auction(seller, id)?;
+			Ok(())
+		}
 
-			let new_game = Game { key_data, owner: owner.clone(), value: None };
-			Games::<T>::insert(key_data, new_game);
-			GamesOwnedBy::<T>::try_mutate(&owner, |owned_games| {
-				owned_games.try_push(key_data).map_err(|_| Error::<T>::TooManyGamesOwned)
-			})?;
-			Self::deposit_event(Event::GameCreated { owner, id: key_data });
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_dutch_auction())]
+		pub fn create_dutch_auction(
+			origin: OriginFor<T>,
+			id: [u8; 32],
+			start_price: T::Balance,
+			floor_price: T::Balance,
+			duration: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			Self::do_create_dutch_auction(seller, id, start_price, floor_price, duration)?;
+			Ok(())
+		}
+
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::make_offer())]
+		pub fn make_offer(
+			origin: OriginFor<T>,
+			id: [u8; 32],
+			amount: T::Balance,
+			expiry: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+			Self::do_make_offer(buyer, id, amount, expiry)?;
+			Ok(())
+		}
+
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::withdraw_offer())]
+		pub fn withdraw_offer(origin: OriginFor<T>, id: [u8; 32]) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+			Self::do_withdraw_offer(buyer, id)?;
 			Ok(())
 		}
 
-		fn perform_transfer(from: T::AccountId, to: T::AccountId, id: [u8; 32]) -> DispatchResult {
-			ensure!(!from.eq(&to), Error::<T>::TransferToSelf);
-			let game = Games::<T>::get(id).ok_or(Error::<T>::GameNotFound)?;
-			ensure!(game.owner == from, Error::<T>::NotAuthorized);
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::accept_offer())]
+		pub fn accept_offer(
+			origin: OriginFor<T>,
+			id: [u8; 32],
+			buyer: T::AccountId,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::do_accept_offer(owner, id, buyer)?;
+			Ok(())
+		}
 
-			Games::<T>::try_mutate(id, |game| -> DispatchResult {
-				let g = game.as_mut().ok_or(Error::<T>::GameNotFound)?;
-				g.owner = to.clone();
-				Ok(())
-			})?;
+		/// Sets the marketplace fee. It may be at most 100% less `MaxRoyalty`, so that the fee and
+		/// a royalty never add up to more than the sale price.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_protocol_fee())]
+		pub fn set_protocol_fee(origin: OriginFor<T>, fee: Permill) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(fee <= Self::max_protocol_fee(), Error::<T>::FeeTooHigh);
+			ProtocolFee::<T>::put(fee);
+			Self::deposit_event(Event::<T>::ProtocolFeeSet { fee });
+			Ok(())
+		}
 
-			GamesOwnedBy::<T>::mutate(&from, |owned_games| {
-				if let Some(index) = owned_games.iter().position(|x| *x == id) {
-					owned_games.remove(index);
-				}
-			});
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_metadata())]
+		pub fn set_metadata(
+			origin: OriginFor<T>,
+			id: [u8; 32],
+			name: BoundedString<T>,
+			uri: BoundedString<T>,
+			attributes: Attributes<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_set_metadata(who, id, name, uri, attributes)?;
+			Ok(())
+		}
 
-			GamesOwnedBy::<T>::try_mutate(&to, |owned_games| {
-				owned_games.try_push(id).map_err(|_| Error::<T>::TooManyGamesOwned)
-			})?;
+		/// Clears unlocked metadata and refunds its deposit. The owner and the creator may call
+		/// this; locked metadata keeps its deposit until the game is burned or changes hands.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::clear_metadata())]
+		pub fn clear_metadata(origin: OriginFor<T>, id: [u8; 32]) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_clear_metadata(who, id)?;
+			Ok(())
+		}
 
-			Self::deposit_event(Event::GameTransferred { from, to, id });
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::lock_metadata())]
+		pub fn lock_metadata(origin: OriginFor<T>, id: [u8; 32]) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_lock_metadata(who, id)?;
 			Ok(())
 		}
 
-		fn update_price(
-			owner: T::AccountId,
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::approve())]
+		pub fn approve(
+			origin: OriginFor<T>,
 			id: [u8; 32],
-			price: Option<T::Balance>,
+			delegate: Option<T::AccountId>,
 		) -> DispatchResult {
-			let mut game = Games::<T>::get(id).ok_or(Error::<T>::GameNotFound)?;
-			ensure!(game.owner == owner, Error::<T>::NotAuthorized);
+			let who = ensure_signed(origin)?;
+			Self::do_approve(who, id, delegate)?;
+			Ok(())
+		}
 
-			game.value = price;
-			Games::<T>::insert(id, game);
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_operator())]
+		pub fn set_operator(
+			origin: OriginFor<T>,
+			operator: T::AccountId,
+			approved: bool,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::do_set_operator(owner, operator, approved)?;
+			Ok(())
+		}
 
-			Self::deposit_event(Event::PriceUpdated { owner, id, price });
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::transfer_from())]
+		pub fn transfer_from(
+			origin: OriginFor<T>,
+			from: T::AccountId,
+			to: T::AccountId,
+			id: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::can_move(&who, &from, &id), Error::<T>::NotAuthorized);
+			Self::do_transfer(from, to, id)?;
 			Ok(())
 		}
 
-		fn purchase_game(
-			buyer: T::AccountId,
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::burn_game())]
+		pub fn burn_game(origin: OriginFor<T>, id: [u8; 32]) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_burn(who, id)?;
+			Ok(())
+		}
+
+		/// Lets `renter` use the game until block `until`. Without a fee the rental starts
+		/// straight away, otherwise once the renter accepts it and pays the fee.
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::lend_game())]
+		pub fn lend_game(
+			origin: OriginFor<T>,
+			id: [u8; 32],
+			renter: T::AccountId,
+			until: BlockNumberFor<T>,
+			fee: Option<T::Balance>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::do_lend(owner, id, renter, until, fee)?;
+			Ok(())
+		}
+
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::accept_rental())]
+		pub fn accept_rental(origin: OriginFor<T>, id: [u8; 32]) -> DispatchResult {
+			let renter = ensure_signed(origin)?;
+			Self::do_accept_rental(renter, id)?;
+			Ok(())
+		}
+
+		/// Creates `count` games with the same royalty. Either every game is created or none is.
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_games(*count))]
+		pub fn create_games(origin: OriginFor<T>, count: u32, royalty: Permill) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			Self::do_create_games(caller, count, royalty)?;
+			Ok(())
+		}
+
+		/// Transfers each game to its paired recipient. Either every transfer succeeds or none
+		/// does.
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::transfer_games(transfers.len() as u32))]
+		pub fn transfer_games(
+			origin: OriginFor<T>,
+			transfers: BoundedVec<(T::AccountId, [u8; 32]), T::MaxBatchSize>,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			Self::do_transfer_games(from, transfers.into_inner())?;
+			Ok(())
+		}
+
+		/// Starts a sealed-bid auction. Bids are committed until `commit_end` and revealed until
+		/// `reveal_end`, when the highest revealed bid wins.
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_sealed_auction())]
+		pub fn create_sealed_auction(
+			origin: OriginFor<T>,
+			id: [u8; 32],
+			reserve: T::Balance,
+			commit_end: BlockNumberFor<T>,
+			reveal_end: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			Self::do_create_sealed_auction(seller, id, reserve, commit_end, reveal_end)?;
+			Ok(())
+		}
+
+		/// Commits to a hidden bid, the hash returned by `sealed_bid_commitment`, and holds
+		/// `deposit`. The deposit must cover the bid and can be larger to hide its size.
+		#[pallet::call_index(24)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::commit_bid())]
+		pub fn commit_bid(
+			origin: OriginFor<T>,
 			id: [u8; 32],
+			commitment: [u8; 32],
+			deposit: T::Balance,
+		) -> DispatchResult {
+			let bidder = ensure_signed(origin)?;
+			Self::do_commit_bid(bidder, id, commitment, deposit)?;
+			Ok(())
+		}
+
+		#[pallet::call_index(25)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::reveal_bid())]
+		pub fn reveal_bid(
+			origin: OriginFor<T>,
+			id: [u8; 32],
+			amount: T::Balance,
+			salt: [u8; 32],
+		) -> DispatchResult {
+			let bidder = ensure_signed(origin)?;
+			Self::do_reveal_bid(bidder, id, amount, salt)?;
+			Ok(())
+		}
+
+		/// Proposes trading the caller's `give` games for `counterparty`'s `want` games, with an
+		/// optional payment in either direction. The proposal is cancelled if one of the `give`
+		/// games changes owner before `counterparty` accepts it, and expires at block `expiry`.
+		#[pallet::call_index(26)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::propose_swap(
+			(give.len() + want.len()) as u32
+		))]
+		pub fn propose_swap(
+			origin: OriginFor<T>,
+			counterparty: T::AccountId,
+			give: SwapGames<T>,
+			want: SwapGames<T>,
+			top_up: Option<TopUp<T::Balance>>,
+			expiry: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let proposer = ensure_signed(origin)?;
+			Self::do_propose_swap(proposer, counterparty, give, want, top_up, expiry)?;
+			Ok(())
+		}
+
+		/// Carries out the swap: every game and the top-up move in one go, or nothing does.
+		#[pallet::call_index(27)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::accept_swap())]
+		pub fn accept_swap(origin: OriginFor<T>, swap_id: u32) -> DispatchResult {
+			let counterparty = ensure_signed(origin)?;
+			Self::do_accept_swap(counterparty, swap_id)?;
+			Ok(())
+		}
+
+		#[pallet::call_index(28)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_swap())]
+		pub fn cancel_swap(origin: OriginFor<T>, swap_id: u32) -> DispatchResult {
+			let proposer = ensure_signed(origin)?;
+			Self::do_cancel_swap(proposer, swap_id)?;
+			Ok(())
+		}
+
+		/// Lists `games` for sale together at `price`. The games cannot be moved, priced or
+		/// auctioned on their own until the bundle is sold or cancelled.
+		#[pallet::call_index(29)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_bundle(games.len() as u32))]
+		pub fn create_bundle(
+			origin: OriginFor<T>,
+			games: BundleGames<T>,
+			price: T::Balance,
+		) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			Self::do_create_bundle(seller, games, price)?;
+			Ok(())
+		}
+
+		#[pallet::call_index(30)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_bundle())]
+		pub fn cancel_bundle(origin: OriginFor<T>, bundle_id: u32) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			Self::do_cancel_bundle(seller, bundle_id)?;
+			Ok(())
+		}
+
+		/// Buys every game in the bundle with a single payment of at most `max_price`.
+		#[pallet::call_index(31)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::buy_bundle())]
+		pub fn buy_bundle(
+			origin: OriginFor<T>,
+			bundle_id: u32,
 			max_price: T::Balance,
 		) -> DispatchResult {
-			let game = Games::<T>::get(id).ok_or(Error::<T>::GameNotFound)?;
-			ensure!(game.value.unwrap_or_default() <= max_price, Error::<T>::PriceTooLow);
+			let buyer = ensure_signed(origin)?;
+			Self::do_buy_bundle(buyer, bundle_id, max_price)?;
+			Ok(())
+		}
+
+		/// Buys a game through an order its owner signed off-chain. The caller pays the order's
+		/// price and receives the game.
+		#[pallet::call_index(32)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::fill_order())]
+		pub fn fill_order(
+			origin: OriginFor<T>,
+			order: OrderOf<T>,
+			signature: T::OffchainSignature,
+		) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+			Self::do_fill_order(buyer, order, signature)?;
+			Ok(())
+		}
 
-			let seller = game.owner.clone();
-			let price = game.value.unwrap_or_default();
+		/// Voids a single order for a game the caller owns, holding `OrderCancellationDeposit`
+		/// until the order expires.
+		#[pallet::call_index(33)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_order())]
+		pub fn cancel_order(origin: OriginFor<T>, order: OrderOf<T>) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			Self::do_cancel_order(seller, order)?;
+			Ok(())
+		}
 
-			ensure!(seller != buyer, Error::<T>::TransferToSelf);
+		/// Voids every order the caller has signed so far.
+		#[pallet::call_index(34)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::bump_order_nonce())]
+		pub fn bump_order_nonce(origin: OriginFor<T>) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			Self::do_bump_order_nonce(seller)?;
+			Ok(())
+		}
 
-			Self::perform_transfer(seller.clone(), buyer.clone(), id)?;
-			Self::deposit_event(Event::GameSold { buyer, id, price });
+		/// Mints a soulbound game for `owner`, who becomes its creator. It can never be
+		/// transferred, sold, swapped or auctioned, only burned.
+		#[pallet::call_index(35)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::issue_soulbound_game())]
+		pub fn issue_soulbound_game(origin: OriginFor<T>, owner: T::AccountId) -> DispatchResult {
+			T::IssuerOrigin::ensure_origin(origin)?;
+			Self::mint(owner, Self::gen_game_key(), Permill::zero(), false)?;
 			Ok(())
 		}
 	}
@@ -0,0 +1,327 @@
+use super::*;
+
+/// Adds the creator and royalty to every game.
+pub mod v1 {
+	use super::*;
+	use frame_support::{
+		migrations::VersionedMigration, storage_alias, traits::UncheckedOnRuntimeUpgrade,
+	};
+	#[cfg(feature = "try-runtime")]
+	use {alloc::vec::Vec, frame_support::sp_runtime::TryRuntimeError};
+
+	#[derive(Decode)]
+	struct OldGame<T: Config> {
+		key_data: [u8; 32],
+		owner: T::AccountId,
+		value: Option<T::Balance>,
+	}
+
+	/// The game layout from v1 up to v3.
+	#[derive(Encode, Decode)]
+	pub(crate) struct GameV1<T: Config> {
+		pub key_data: [u8; 32],
+		pub owner: T::AccountId,
+		pub creator: T::AccountId,
+		pub value: Option<T::Balance>,
+		pub royalty: Permill,
+	}
+
+	#[storage_alias]
+	pub(crate) type Games<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, [u8; 32], GameV1<T>>;
+
+	pub struct InnerMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok((Games::<T>::iter_keys().count() as u32).encode())
+		}
+
+		/// Existing games are credited to their current owner, with no royalty.
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated = 0u64;
+			Games::<T>::translate::<OldGame<T>, _>(|_, old| {
+				translated += 1;
+				Some(GameV1 {
+					key_data: old.key_data,
+					owner: old.owner.clone(),
+					creator: old.owner,
+					value: old.value,
+					royalty: Permill::zero(),
+				})
+			});
+			T::DbWeight::get().reads_writes(translated, translated)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let count = u32::decode(&mut &state[..])
+				.map_err(|_| TryRuntimeError::Other("invalid pre-upgrade state"))?;
+			ensure!(
+				Games::<T>::iter_values().count() as u32 == count,
+				TryRuntimeError::Other("games were lost in the migration")
+			);
+			ensure!(
+				Games::<T>::iter_values().all(|game| game.creator == game.owner),
+				TryRuntimeError::Other("creator differs from owner after the migration")
+			);
+			Ok(())
+		}
+	}
+
+	/// Migrates `Games` to the v1 layout and bumps the storage version.
+	pub type MigrateV0ToV1<T> = VersionedMigration<
+		0,
+		1,
+		InnerMigrateV0ToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Starts tracking the live supply of games separately from the number ever minted.
+pub mod v2 {
+	use super::*;
+	use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade};
+	#[cfg(feature = "try-runtime")]
+	use {alloc::vec::Vec, frame_support::sp_runtime::TryRuntimeError};
+
+	pub struct InnerMigrateV1ToV2<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+		/// No game could be burned before v2, so every game stored is live.
+		fn on_runtime_upgrade() -> Weight {
+			let live = Games::<T>::iter_keys().count() as u64;
+			LiveGameCount::<T>::put(live as u32);
+			T::DbWeight::get().reads_writes(live, 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(
+				LiveGameCount::<T>::get() == Games::<T>::iter_keys().count() as u32,
+				TryRuntimeError::Other("live supply does not match the stored games")
+			);
+			ensure!(
+				LiveGameCount::<T>::get() <= GameCount::<T>::get(),
+				TryRuntimeError::Other("more games live than were ever minted")
+			);
+			Ok(())
+		}
+	}
+
+	/// Initialises `LiveGameCount` and bumps the storage version.
+	pub type MigrateV1ToV2<T> = VersionedMigration<
+		1,
+		2,
+		InnerMigrateV1ToV2<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Replaces the per-owner vectors in `GamesOwnedBy` with a double map keyed by owner and game.
+pub mod v3 {
+	use super::*;
+	use alloc::vec::Vec;
+	#[cfg(feature = "try-runtime")]
+	use frame_support::sp_runtime::TryRuntimeError;
+	use frame_support::{
+		migrations::VersionedMigration, storage_alias, traits::UncheckedOnRuntimeUpgrade,
+	};
+
+	#[storage_alias]
+	pub(crate) type GamesOwnedBy<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		BoundedVec<[u8; 32], ConstU32<100>>,
+		ValueQuery,
+	>;
+
+	pub struct InnerMigrateV2ToV3<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV2ToV3<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let owned: Vec<(T::AccountId, [u8; 32])> = GamesOwnedBy::<T>::iter()
+				.flat_map(|(owner, ids)| ids.into_iter().map(move |id| (owner.clone(), id)))
+				.collect();
+			Ok(owned.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			// Old and new entries share a prefix, so every vector is drained before the first
+			// index entry is written.
+			let old: Vec<_> = GamesOwnedBy::<T>::drain().collect();
+			let owners = old.len() as u64;
+			let mut games = 0u64;
+			for (owner, ids) in old {
+				for id in ids {
+					games += 1;
+					crate::GamesOwnedBy::<T>::insert(&owner, id, ());
+				}
+			}
+			T::DbWeight::get().reads_writes(owners, owners.saturating_add(games))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let owned = Vec::<(T::AccountId, [u8; 32])>::decode(&mut &state[..])
+				.map_err(|_| TryRuntimeError::Other("invalid pre-upgrade state"))?;
+			ensure!(
+				owned
+					.iter()
+					.all(|(owner, id)| crate::GamesOwnedBy::<T>::contains_key(owner, id)),
+				TryRuntimeError::Other("ownership was lost in the migration")
+			);
+			ensure!(
+				crate::GamesOwnedBy::<T>::iter_keys().count() == owned.len(),
+				TryRuntimeError::Other("ownership was invented in the migration")
+			);
+			Ok(())
+		}
+	}
+
+	/// Migrates `GamesOwnedBy` to the v3 layout and bumps the storage version.
+	pub type MigrateV2ToV3<T> = VersionedMigration<
+		2,
+		3,
+		InnerMigrateV2ToV3<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Adds an optional rental to every game.
+pub mod v4 {
+	use super::*;
+	use frame_support::{
+		migrations::VersionedMigration, storage_alias, traits::UncheckedOnRuntimeUpgrade,
+	};
+	#[cfg(feature = "try-runtime")]
+	use {alloc::vec::Vec, frame_support::sp_runtime::TryRuntimeError};
+
+	/// The game layout in v4.
+	#[derive(Encode, Decode)]
+	pub(crate) struct GameV4<T: Config> {
+		pub key_data: [u8; 32],
+		pub owner: T::AccountId,
+		pub creator: T::AccountId,
+		pub value: Option<T::Balance>,
+		pub royalty: Permill,
+		pub rental: Option<Rental<T>>,
+	}
+
+	#[storage_alias]
+	pub(crate) type Games<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, [u8; 32], GameV4<T>>;
+
+	pub struct InnerMigrateV3ToV4<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV3ToV4<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok((Games::<T>::iter_keys().count() as u32).encode())
+		}
+
+		/// No game is rented out before v4.
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated = 0u64;
+			Games::<T>::translate::<v1::GameV1<T>, _>(|_, old| {
+				translated += 1;
+				Some(GameV4 {
+					key_data: old.key_data,
+					owner: old.owner,
+					creator: old.creator,
+					value: old.value,
+					royalty: old.royalty,
+					rental: None,
+				})
+			});
+			T::DbWeight::get().reads_writes(translated, translated)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let count = u32::decode(&mut &state[..])
+				.map_err(|_| TryRuntimeError::Other("invalid pre-upgrade state"))?;
+			ensure!(
+				Games::<T>::iter_values().count() as u32 == count,
+				TryRuntimeError::Other("games were lost in the migration")
+			);
+			ensure!(
+				Games::<T>::iter_values().all(|game| game.rental.is_none()),
+				TryRuntimeError::Other("a game is rented out after the migration")
+			);
+			Ok(())
+		}
+	}
+
+	/// Migrates `Games` to the v4 layout and bumps the storage version.
+	pub type MigrateV3ToV4<T> = VersionedMigration<
+		3,
+		4,
+		InnerMigrateV3ToV4<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Marks every existing game as transferable.
+pub mod v5 {
+	use super::*;
+	use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade};
+	#[cfg(feature = "try-runtime")]
+	use {alloc::vec::Vec, frame_support::sp_runtime::TryRuntimeError};
+
+	pub struct InnerMigrateV4ToV5<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV4ToV5<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok((Games::<T>::iter_keys().count() as u32).encode())
+		}
+
+		/// Soulbound games do not exist before v5.
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated = 0u64;
+			Games::<T>::translate::<v4::GameV4<T>, _>(|_, old| {
+				translated += 1;
+				Some(Game {
+					key_data: old.key_data,
+					owner: old.owner,
+					creator: old.creator,
+					value: old.value,
+					royalty: old.royalty,
+					rental: old.rental,
+					transferable: true,
+				})
+			});
+			T::DbWeight::get().reads_writes(translated, translated)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let count = u32::decode(&mut &state[..])
+				.map_err(|_| TryRuntimeError::Other("invalid pre-upgrade state"))?;
+			ensure!(
+				Games::<T>::iter_values().count() as u32 == count,
+				TryRuntimeError::Other("games were lost in the migration")
+			);
+			ensure!(
+				Games::<T>::iter_values().all(|game| game.transferable),
+				TryRuntimeError::Other("a game is soulbound after the migration")
+			);
+			Ok(())
+		}
+	}
+
+	/// Migrates `Games` to the v5 layout and bumps the storage version.
+	pub type MigrateV4ToV5<T> = VersionedMigration<
+		4,
+		5,
+		InnerMigrateV4ToV5<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
@@ -1,5 +1,13 @@
 use crate as pallet_template;
-use frame_support::derive_impl;
+use frame::arithmetic::Permill;
+use frame::deps::sp_runtime::testing::{TestSignature, UintAuthorityId};
+use frame::primitives::{BlakeTwo256, H256};
+use frame::traits::{Hash, Randomness};
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstU32, ConstU64},
+	weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight},
+};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -23,7 +31,78 @@ impl frame_system::Config for Test {
 	type AccountData = pallet_balances::AccountData<u64>;
 }
 
+parameter_types! {
+	pub const MaxRoyalty: Permill = Permill::from_percent(25);
+	pub const DefaultProtocolFee: Permill = Permill::from_percent(1);
+	pub const FeeDestination: u64 = 99;
+	pub const MetadataDepositBase: u64 = 10;
+	pub const MetadataDepositPerByte: u64 = 1;
+	pub const SwapDeposit: u64 = 10;
+	pub MaxSettlementWeight: Weight = Weight::from_parts(WEIGHT_REF_TIME_PER_SECOND / 4, u64::MAX);
+	pub const SealedBidPolicy: pallet_template::UnrevealedBidPolicy =
+		pallet_template::UnrevealedBidPolicy::Slash;
+}
+
+/// Deterministic randomness: the hash of the subject.
+pub struct TestRandomness;
+impl Randomness<H256, u64> for TestRandomness {
+	fn random(subject: &[u8]) -> (H256, u64) {
+		(BlakeTwo256::hash(subject), System::block_number())
+	}
+}
+
 impl pallet_template::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
+	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Randomness = TestRandomness;
+	type MaxAuctionsPerBlock = ConstU32<50>;
+	type MaxSettlementWeight = MaxSettlementWeight;
+	type MaxSealedBids = ConstU32<10>;
+	type UnrevealedBidPolicy = SealedBidPolicy;
+	type MaxOffersPerGame = ConstU32<10>;
+	type MaxOffersPerBlock = ConstU32<50>;
+	type MinOffer = ConstU64<1>;
+	type MaxOfferDuration = ConstU64<10_000>;
+	type MaxListingsPerBlock = ConstU32<50>;
+	type MaxListingsScanned = ConstU32<1_000>;
+	type MaxSwapGames = ConstU32<5>;
+	type MaxSwapsPerGame = ConstU32<5>;
+	type MaxSwapsPerBlock = ConstU32<50>;
+	type SwapDeposit = SwapDeposit;
+	type MaxSwapDuration = ConstU64<10_000>;
+	type MaxBundleSize = ConstU32<10>;
+	type MaxBatchSize = ConstU32<50>;
+	type MaxRoyalty = MaxRoyalty;
+	type DefaultProtocolFee = DefaultProtocolFee;
+	type FeeDestination = FeeDestination;
+	type StringLimit = ConstU32<64>;
+	type MaxAttributes = ConstU32<16>;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type IssuerOrigin = frame_system::EnsureRoot<u64>;
+	type OffchainSignature = TestSignature;
+	type OffchainPublic = UintAuthorityId;
+	type OrderCancellationDeposit = ConstU64<10>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_template::BenchmarkHelper<UintAuthorityId, u64, TestSignature> for () {
+	fn signer() -> (UintAuthorityId, u64) {
+		(UintAuthorityId(1_000), 1_000)
+	}
+
+	fn sign(signer: &UintAuthorityId, message: &[u8]) -> TestSignature {
+		TestSignature(signer.0, message.to_vec())
+	}
+}
+
+// Used by the benchmark test suite.
+#[cfg(feature = "runtime-benchmarks")]
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	use sp_runtime::BuildStorage;
+	frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
 }
@@ -3,17 +3,34 @@
 use crate::*;
 use crate::{self as pallet_games};
 use frame::deps::sp_io;
+use frame::primitives::{BlakeTwo256, H256};
 use frame::runtime::prelude::*;
 use frame::testing_prelude::*;
 use frame::traits::fungible::*;
+use frame::traits::tokens::{Fortitude, Precision};
+use frame::traits::Hash;
+use frame_support::weights::constants::WEIGHT_REF_TIME_PER_SECOND;
+use sp_runtime::testing::{TestSignature, UintAuthorityId};
+use sp_runtime::TokenError;
 
 type Balance = u64;
 type Block = frame_system::mocking::MockBlock<TestRuntime>;
 
 const ALICE: u64 = 1;
 const BOB: u64 = 2;
+const CHARLIE: u64 = 3;
+const DAVE: u64 = 4;
+const TREASURY: u64 = 99;
 
-const DEFAULT_GAME: Game<TestRuntime> = Game { key_data: [0u8; 32], owner: 0, value: None };
+const DEFAULT_GAME: Game<TestRuntime> = Game {
+	key_data: [0u8; 32],
+	owner: 0,
+	creator: 0,
+	value: None,
+	royalty: Permill::zero(),
+	rental: None,
+	transferable: true,
+};
 
 construct_runtime! {
 	pub struct TestRuntime {
@@ -33,11 +50,67 @@ impl frame_system::Config for TestRuntime {
 impl pallet_balances::Config for TestRuntime {
 	type AccountStore = System;
 	type Balance = Balance;
+	type ExistentialDeposit = ExistentialDeposit;
 }
 
 impl pallet_games::Config for TestRuntime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
+	type Currency = PalletBalances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Randomness = TestRandomness;
+	type MaxAuctionsPerBlock = ConstU32<2>;
+	type MaxSettlementWeight = MaxSettlementWeight;
+	type MaxSealedBids = ConstU32<3>;
+	type UnrevealedBidPolicy = SealedBidPolicy;
+	type MaxOffersPerGame = ConstU32<2>;
+	type MaxOffersPerBlock = ConstU32<3>;
+	type MinOffer = ConstU64<5>;
+	type MaxOfferDuration = ConstU64<100>;
+	type MaxListingsPerBlock = ConstU32<2>;
+	type MaxListingsScanned = ConstU32<4>;
+	type MaxSwapGames = ConstU32<2>;
+	type MaxSwapsPerGame = ConstU32<2>;
+	type MaxSwapsPerBlock = ConstU32<2>;
+	type SwapDeposit = SwapDeposit;
+	type MaxSwapDuration = ConstU64<100>;
+	type MaxBundleSize = ConstU32<3>;
+	type MaxBatchSize = ConstU32<3>;
+	type MaxRoyalty = MaxRoyalty;
+	type DefaultProtocolFee = DefaultProtocolFee;
+	type FeeDestination = FeeDestination;
+	type StringLimit = ConstU32<8>;
+	type MaxAttributes = ConstU32<2>;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type IssuerOrigin = frame_system::EnsureRoot<u64>;
+	type OffchainSignature = TestSignature;
+	type OffchainPublic = UintAuthorityId;
+	type OrderCancellationDeposit = ConstU64<3>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+parameter_types! {
+	pub const MaxRoyalty: Permill = Permill::from_percent(25);
+	pub const DefaultProtocolFee: Permill = Permill::zero();
+	pub const FeeDestination: u64 = TREASURY;
+	pub const MetadataDepositBase: u64 = 10;
+	pub const MetadataDepositPerByte: u64 = 1;
+	pub const SwapDeposit: u64 = 5;
+	pub static SealedBidPolicy: UnrevealedBidPolicy = UnrevealedBidPolicy::Refund;
+	pub static RandomSeed: H256 = H256::zero();
+	pub static ExistentialDeposit: u64 = 1;
+	pub static MaxSettlementWeight: Weight =
+		Weight::from_parts(WEIGHT_REF_TIME_PER_SECOND / 4, u64::MAX);
+}
+
+/// Deterministic randomness derived from `RandomSeed`, which a test may change.
+pub struct TestRandomness;
+impl Randomness<H256, u64> for TestRandomness {
+	fn random(subject: &[u8]) -> (H256, u64) {
+		(BlakeTwo256::hash_of(&(RandomSeed::get(), subject)), System::block_number())
+	}
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -47,9 +120,39 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 		.into()
 }
 
+/// Runs `test` in a fresh externality, then checks that the pallet's storage is consistent.
+fn build_and_execute(test: impl FnOnce()) {
+	new_test_ext().execute_with(|| {
+		test();
+		PalletGames::do_try_state().unwrap();
+	});
+}
+
+fn create_game_for(owner: u64) -> [u8; 32] {
+	let id = PalletGames::gen_game_key();
+	assert_ok!(PalletGames::create_game(RuntimeOrigin::signed(owner), Permill::zero()));
+	id
+}
+
+fn owned_by(owner: u64) -> Vec<[u8; 32]> {
+	GamesOwnedBy::<TestRuntime>::iter_key_prefix(owner).collect()
+}
+
+fn bounded(s: &str) -> BoundedString<TestRuntime> {
+	s.as_bytes().to_vec().try_into().unwrap()
+}
+
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		let next = System::block_number() + 1;
+		System::set_block_number(next);
+		PalletGames::on_initialize(next);
+	}
+}
+
 #[test]
 fn balances_functionality_works() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		System::set_block_number(1);
 		assert_ok!(PalletBalances::mint_into(&ALICE, 100));
 		assert_ok!(PalletBalances::mint_into(&BOB, 100));
@@ -58,17 +161,20 @@ fn balances_functionality_works() {
 
 #[test]
 fn verify_signed_origin_for_game_creation() {
-	new_test_ext().execute_with(|| {
-		assert_ok!(PalletGames::create_game(RuntimeOrigin::signed(ALICE)));
-		assert_noop!(PalletGames::create_game(RuntimeOrigin::none()), DispatchError::BadOrigin);
+	build_and_execute(|| {
+		assert_ok!(PalletGames::create_game(RuntimeOrigin::signed(ALICE), Permill::zero()));
+		assert_noop!(
+			PalletGames::create_game(RuntimeOrigin::none(), Permill::zero()),
+			DispatchError::BadOrigin
+		);
 	});
 }
 
 #[test]
 fn event_emitted_on_game_creation() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		System::set_block_number(1);
-		assert_ok!(PalletGames::create_game(RuntimeOrigin::signed(ALICE)));
+		assert_ok!(PalletGames::create_game(RuntimeOrigin::signed(ALICE), Permill::zero()));
 		let last_event = System::events().pop().expect("Event expected").event;
 		match last_event {
 			RuntimeEvent::PalletGames(Event::GameCreated { owner, .. }) => {
@@ -81,7 +187,7 @@ fn event_emitted_on_game_creation() {
 
 #[test]
 fn game_counter_increases_correctly() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_eq!(GameCount::<TestRuntime>::get(), u32::default());
 		GameCount::<TestRuntime>::set(1337u32);
 		assert_eq!(GameCount::<TestRuntime>::get(), 1337u32);
@@ -93,10 +199,10 @@ fn game_counter_increases_correctly() {
 
 #[test]
 fn test_game_counter_increment_on_game_creation() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		assert_eq!(GameCount::<TestRuntime>::get(), u32::default());
 		System::set_block_number(1);
-		assert_ok!(PalletGames::mint(ALICE, [0u8; 32]));
+		assert_ok!(PalletGames::mint(ALICE, [0u8; 32], Permill::zero(), true));
 		assert_eq!(GameCount::<TestRuntime>::get(), 1);
 	});
 }
@@ -113,25 +219,28 @@ fn validate_game_added_to_map_on_creation() {
 
 #[test]
 fn game_map_increments_on_successful_creation() {
-	new_test_ext().execute_with(|| {
-		assert_ok!(PalletGames::create_game(RuntimeOrigin::signed(ALICE)));
+	build_and_execute(|| {
+		assert_ok!(PalletGames::create_game(RuntimeOrigin::signed(ALICE), Permill::zero()));
 		assert_eq!(Games::<TestRuntime>::iter().count(), 1);
 	});
 }
 
 #[test]
 fn prevent_duplicate_game_creation() {
-	new_test_ext().execute_with(|| {
-		assert_ok!(PalletGames::mint(ALICE, [0u8; 32]));
+	build_and_execute(|| {
+		assert_ok!(PalletGames::mint(ALICE, [0u8; 32], Permill::zero(), true));
 		assert_eq!(Games::<TestRuntime>::iter().count(), 1);
-		assert_noop!(PalletGames::mint(BOB, [0u8; 32]), Error::<TestRuntime>::DuplicatedGame);
+		assert_noop!(
+			PalletGames::mint(BOB, [0u8; 32], Permill::zero(), true),
+			Error::<TestRuntime>::DuplicatedGame
+		);
 		assert_eq!(Games::<TestRuntime>::iter().count(), 1);
 	});
 }
 
 #[test]
 fn test_game_struct_encoding_and_decoding() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		let game = DEFAULT_GAME;
 		let bytes = game.encode();
 		let _decoded_game = Game::<TestRuntime>::decode(&mut &bytes[..]).unwrap();
@@ -142,8 +251,8 @@ fn test_game_struct_encoding_and_decoding() {
 
 #[test]
 fn verify_owner_in_game_struct_after_creation() {
-	new_test_ext().execute_with(|| {
-		assert_ok!(PalletGames::mint(1337, [42u8; 32]));
+	build_and_execute(|| {
+		assert_ok!(PalletGames::mint(1337, [42u8; 32], Permill::zero(), true));
 		let game = Games::<TestRuntime>::get([42u8; 32]).unwrap();
 		assert_eq!(game.owner, 1337);
 		assert_eq!(game.key_data, [42u8; 32]);
@@ -152,32 +261,29 @@ fn verify_owner_in_game_struct_after_creation() {
 
 #[test]
 fn validate_multiple_games_owned_by_user() {
-	new_test_ext().execute_with(|| {
-		assert_eq!(GamesOwnedBy::<TestRuntime>::get(ALICE).len(), 0);
-		assert_ok!(PalletGames::create_game(RuntimeOrigin::signed(ALICE)));
-		assert_ok!(PalletGames::create_game(RuntimeOrigin::signed(ALICE)));
-		assert_eq!(GamesOwnedBy::<TestRuntime>::get(ALICE).len(), 2);
+	build_and_execute(|| {
+		assert_eq!(owned_by(ALICE).len(), 0);
+		assert_ok!(PalletGames::create_game(RuntimeOrigin::signed(ALICE), Permill::zero()));
+		assert_ok!(PalletGames::create_game(RuntimeOrigin::signed(ALICE), Permill::zero()));
+		assert_eq!(owned_by(ALICE).len(), 2);
 	});
 }
 
 #[test]
-fn prevent_user_from_owning_too_many_games() {
-	new_test_ext().execute_with(|| {
-		for _ in 0..100 {
-			assert_ok!(PalletGames::create_game(RuntimeOrigin::signed(ALICE)));
+fn users_can_own_any_number_of_games() {
+	build_and_execute(|| {
+		for _ in 0..150 {
+			assert_ok!(PalletGames::create_game(RuntimeOrigin::signed(ALICE), Permill::zero()));
 		}
-		assert_noop!(
-			PalletGames::create_game(RuntimeOrigin::signed(1)),
-			Error::<TestRuntime>::TooManyGamesOwned
-		);
+		assert_eq!(owned_by(ALICE).len(), 150);
 	});
 }
 
 #[test]
 fn transfer_game_emits_event_successfully() {
-	new_test_ext().execute_with(|| {
+	build_and_execute(|| {
 		System::set_block_number(1);
-		assert_ok!(PalletGames::create_game(RuntimeOrigin::signed(ALICE)));
+		assert_ok!(PalletGames::create_game(RuntimeOrigin::signed(ALICE), Permill::zero()));
 		let game_id = Games::<TestRuntime>::iter_keys().next().unwrap();
 		assert_ok!(PalletGames::transfer_game(RuntimeOrigin::signed(ALICE), BOB, game_id));
 		System::assert_last_event(
@@ -188,14 +294,14 @@ fn transfer_game_emits_event_successfully() {
 
 #[test]
 fn game_transfer_logic_verification() {
-	new_test_ext().execute_with(|| {
-		assert_ok!(PalletGames::create_game(RuntimeOrigin::signed(ALICE)));
+	build_and_execute(|| {
+		assert_ok!(PalletGames::create_game(RuntimeOrigin::signed(ALICE), Permill::zero()));
 		let game = Games::<TestRuntime>::iter_values().next().unwrap();
 		let game_id = game.key_data;
 
 		assert_eq!(game.owner, ALICE);
-		assert_eq!(GamesOwnedBy::<TestRuntime>::get(ALICE), vec![game_id]);
-		assert_eq!(GamesOwnedBy::<TestRuntime>::get(BOB), vec![]);
+		assert_eq!(owned_by(ALICE), vec![game_id]);
+		assert!(owned_by(BOB).is_empty());
 
 		assert_noop!(
 			PalletGames::do_transfer(ALICE, ALICE, game_id),
@@ -213,3 +319,2818 @@ fn game_transfer_logic_verification() {
 		);
 	});
 }
+
+#[test]
+fn buy_game_moves_funds_from_buyer_to_seller() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&ALICE, 100));
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		assert_ok!(PalletGames::create_game(RuntimeOrigin::signed(ALICE), Permill::zero()));
+		let game_id = Games::<TestRuntime>::iter_keys().next().unwrap();
+		assert_ok!(PalletGames::set_game_price(
+			RuntimeOrigin::signed(ALICE),
+			game_id,
+			Some(40),
+			None
+		));
+
+		assert_ok!(PalletGames::buy_game(RuntimeOrigin::signed(BOB), game_id, 50));
+
+		assert_eq!(PalletBalances::balance(&ALICE), 140);
+		assert_eq!(PalletBalances::balance(&BOB), 60);
+		let game = Games::<TestRuntime>::get(game_id).unwrap();
+		assert_eq!(game.owner, BOB);
+		assert_eq!(game.value, None);
+		assert!(owned_by(ALICE).is_empty());
+		assert_eq!(owned_by(BOB), vec![game_id]);
+		System::assert_last_event(
+			Event::<TestRuntime>::GameSold { buyer: BOB, id: game_id, price: 40 }.into(),
+		);
+	});
+}
+
+#[test]
+fn buy_game_rejects_unlisted_game() {
+	build_and_execute(|| {
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		assert_ok!(PalletGames::create_game(RuntimeOrigin::signed(ALICE), Permill::zero()));
+		let game_id = Games::<TestRuntime>::iter_keys().next().unwrap();
+
+		assert_noop!(
+			PalletGames::buy_game(RuntimeOrigin::signed(BOB), game_id, 50),
+			Error::<TestRuntime>::NotForSale
+		);
+	});
+}
+
+#[test]
+fn buy_game_respects_max_price() {
+	build_and_execute(|| {
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		assert_ok!(PalletGames::create_game(RuntimeOrigin::signed(ALICE), Permill::zero()));
+		let game_id = Games::<TestRuntime>::iter_keys().next().unwrap();
+		assert_ok!(PalletGames::set_game_price(
+			RuntimeOrigin::signed(ALICE),
+			game_id,
+			Some(40),
+			None
+		));
+
+		assert_noop!(
+			PalletGames::buy_game(RuntimeOrigin::signed(BOB), game_id, 39),
+			Error::<TestRuntime>::PriceTooLow
+		);
+		assert_noop!(
+			PalletGames::buy_game(RuntimeOrigin::signed(ALICE), game_id, 40),
+			Error::<TestRuntime>::TransferToSelf
+		);
+	});
+}
+
+#[test]
+fn buy_game_rolls_back_when_buyer_cannot_pay() {
+	build_and_execute(|| {
+		assert_ok!(PalletBalances::mint_into(&ALICE, 100));
+		assert_ok!(PalletBalances::mint_into(&BOB, 30));
+		assert_ok!(PalletGames::create_game(RuntimeOrigin::signed(ALICE), Permill::zero()));
+		let game_id = Games::<TestRuntime>::iter_keys().next().unwrap();
+		assert_ok!(PalletGames::set_game_price(
+			RuntimeOrigin::signed(ALICE),
+			game_id,
+			Some(40),
+			None
+		));
+
+		assert_noop!(
+			PalletGames::buy_game(RuntimeOrigin::signed(BOB), game_id, 40),
+			TokenError::FundsUnavailable
+		);
+
+		assert_eq!(PalletBalances::balance(&ALICE), 100);
+		assert_eq!(PalletBalances::balance(&BOB), 30);
+		let game = Games::<TestRuntime>::get(game_id).unwrap();
+		assert_eq!(game.owner, ALICE);
+		assert_eq!(game.value, Some(40));
+	});
+}
+
+#[test]
+fn storage_is_bounded_and_versioned() {
+	use frame::traits::{GetStorageVersion, StorageInfoTrait};
+
+	build_and_execute(|| {
+		for info in PalletGames::storage_info() {
+			assert!(info.max_size.is_some(), "unbounded storage item {:?}", info.storage_name);
+		}
+		assert_eq!(PalletGames::in_code_storage_version(), 5);
+	});
+}
+
+#[test]
+fn auction_settles_to_highest_bidder() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&ALICE, 100));
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		assert_ok!(PalletBalances::mint_into(&CHARLIE, 100));
+		let game_id = create_game_for(ALICE);
+
+		assert_ok!(PalletGames::create_auction(RuntimeOrigin::signed(ALICE), game_id, 10, 5));
+		assert_ok!(PalletGames::bid(RuntimeOrigin::signed(BOB), game_id, 20));
+		assert_eq!(PalletBalances::total_balance_on_hold(&BOB), 20);
+
+		assert_ok!(PalletGames::bid(RuntimeOrigin::signed(CHARLIE), game_id, 30));
+		assert_eq!(PalletBalances::total_balance_on_hold(&BOB), 0);
+		assert_eq!(PalletBalances::total_balance_on_hold(&CHARLIE), 30);
+
+		run_to_block(5);
+
+		assert_eq!(Games::<TestRuntime>::get(game_id).unwrap().owner, CHARLIE);
+		assert!(!Auctions::<TestRuntime>::contains_key(game_id));
+		assert_eq!(PalletBalances::balance(&ALICE), 130);
+		assert_eq!(PalletBalances::balance(&BOB), 100);
+		assert_eq!(PalletBalances::total_balance(&CHARLIE), 70);
+		System::assert_last_event(
+			Event::<TestRuntime>::AuctionSettled { winner: CHARLIE, id: game_id, price: 30 }.into(),
+		);
+	});
+}
+
+#[test]
+fn auction_locks_game_until_it_ends() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		let game_id = create_game_for(ALICE);
+		assert_ok!(PalletGames::set_game_price(
+			RuntimeOrigin::signed(ALICE),
+			game_id,
+			Some(40),
+			None
+		));
+
+		assert_ok!(PalletGames::create_auction(RuntimeOrigin::signed(ALICE), game_id, 10, 3));
+		assert_eq!(Games::<TestRuntime>::get(game_id).unwrap().value, None);
+
+		assert_noop!(
+			PalletGames::transfer_game(RuntimeOrigin::signed(ALICE), BOB, game_id),
+			Error::<TestRuntime>::GameLocked
+		);
+		assert_noop!(
+			PalletGames::set_game_price(RuntimeOrigin::signed(ALICE), game_id, Some(40), None),
+			Error::<TestRuntime>::GameLocked
+		);
+		assert_noop!(
+			PalletGames::create_auction(RuntimeOrigin::signed(ALICE), game_id, 10, 4),
+			Error::<TestRuntime>::GameLocked
+		);
+		assert_noop!(
+			PalletGames::buy_game(RuntimeOrigin::signed(BOB), game_id, 40),
+			Error::<TestRuntime>::NotForSale
+		);
+
+		run_to_block(3);
+
+		System::assert_last_event(Event::<TestRuntime>::AuctionEnded { id: game_id }.into());
+		assert_ok!(PalletGames::transfer_game(RuntimeOrigin::signed(ALICE), BOB, game_id));
+	});
+}
+
+#[test]
+fn bids_are_validated() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		assert_ok!(PalletBalances::mint_into(&CHARLIE, 15));
+		let game_id = create_game_for(ALICE);
+
+		assert_noop!(
+			PalletGames::bid(RuntimeOrigin::signed(BOB), game_id, 20),
+			Error::<TestRuntime>::AuctionNotFound
+		);
+		assert_noop!(
+			PalletGames::create_auction(RuntimeOrigin::signed(BOB), game_id, 10, 3),
+			Error::<TestRuntime>::NotAuthorized
+		);
+		assert_noop!(
+			PalletGames::create_auction(RuntimeOrigin::signed(ALICE), game_id, 10, 1),
+			Error::<TestRuntime>::AuctionEndInPast
+		);
+		assert_ok!(PalletGames::create_auction(RuntimeOrigin::signed(ALICE), game_id, 10, 3));
+
+		assert_noop!(
+			PalletGames::bid(RuntimeOrigin::signed(ALICE), game_id, 20),
+			Error::<TestRuntime>::TransferToSelf
+		);
+		assert_noop!(
+			PalletGames::bid(RuntimeOrigin::signed(BOB), game_id, 9),
+			Error::<TestRuntime>::BidTooLow
+		);
+		assert_ok!(PalletGames::bid(RuntimeOrigin::signed(BOB), game_id, 12));
+		assert_noop!(
+			PalletGames::bid(RuntimeOrigin::signed(CHARLIE), game_id, 12),
+			Error::<TestRuntime>::BidTooLow
+		);
+		assert_noop!(
+			PalletGames::bid(RuntimeOrigin::signed(CHARLIE), game_id, 20),
+			TokenError::FundsUnavailable
+		);
+
+		System::set_block_number(3);
+		assert_noop!(
+			PalletGames::bid(RuntimeOrigin::signed(CHARLIE), game_id, 14),
+			Error::<TestRuntime>::AuctionClosed
+		);
+	});
+}
+
+#[test]
+fn cancel_auction_only_without_bids() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		let first = create_game_for(ALICE);
+		let second = create_game_for(ALICE);
+		assert_ok!(PalletGames::create_auction(RuntimeOrigin::signed(ALICE), first, 10, 3));
+		assert_ok!(PalletGames::create_auction(RuntimeOrigin::signed(ALICE), second, 10, 3));
+		assert_ok!(PalletGames::bid(RuntimeOrigin::signed(BOB), second, 10));
+
+		assert_noop!(
+			PalletGames::cancel_auction(RuntimeOrigin::signed(BOB), first),
+			Error::<TestRuntime>::NotAuthorized
+		);
+		assert_noop!(
+			PalletGames::cancel_auction(RuntimeOrigin::signed(ALICE), second),
+			Error::<TestRuntime>::AuctionHasBids
+		);
+		assert_ok!(PalletGames::cancel_auction(RuntimeOrigin::signed(ALICE), first));
+
+		assert!(!Auctions::<TestRuntime>::contains_key(first));
+		assert_eq!(AuctionsEndingAt::<TestRuntime>::get(3).into_inner(), vec![second]);
+		assert_ok!(PalletGames::transfer_game(RuntimeOrigin::signed(ALICE), BOB, first));
+	});
+}
+
+#[test]
+fn auctions_ending_per_block_are_bounded() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		for _ in 0..2 {
+			let game_id = create_game_for(ALICE);
+			assert_ok!(PalletGames::create_auction(RuntimeOrigin::signed(ALICE), game_id, 10, 3));
+		}
+		let game_id = create_game_for(ALICE);
+		assert_noop!(
+			PalletGames::create_auction(RuntimeOrigin::signed(ALICE), game_id, 10, 3),
+			Error::<TestRuntime>::TooManyAuctionsEnding
+		);
+		assert_ok!(PalletGames::create_auction(RuntimeOrigin::signed(ALICE), game_id, 10, 4));
+	});
+}
+
+#[test]
+fn auctions_that_do_not_fit_the_settlement_budget_settle_later() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		let ids = [create_game_for(ALICE), create_game_for(ALICE)];
+		for id in ids {
+			assert_ok!(PalletGames::create_auction(RuntimeOrigin::signed(ALICE), id, 10, 3));
+			assert_ok!(PalletGames::bid(RuntimeOrigin::signed(BOB), id, 20));
+		}
+		// Leave room for a single settlement per block.
+		let db: frame_support::weights::RuntimeDbWeight =
+			<TestRuntime as frame_system::Config>::DbWeight::get();
+		MaxSettlementWeight::set(
+			db.reads_writes(1, 1).saturating_add(<() as WeightInfo>::settle_auction()),
+		);
+
+		run_to_block(3);
+		assert_eq!(Games::<TestRuntime>::get(ids[0]).unwrap().owner, BOB);
+		assert_eq!(Games::<TestRuntime>::get(ids[1]).unwrap().owner, ALICE);
+		assert_eq!(AuctionsEndingAt::<TestRuntime>::get(3).into_inner(), vec![ids[1]]);
+
+		run_to_block(4);
+		assert_eq!(Games::<TestRuntime>::get(ids[1]).unwrap().owner, BOB);
+		assert!(!AuctionsEndingAt::<TestRuntime>::contains_key(3));
+		assert_eq!(PalletBalances::balance(&ALICE), 40);
+		assert_eq!(AuctionSettlementCursor::<TestRuntime>::get(), Some(3));
+
+		// The cursor catches up once the backlog is gone.
+		MaxSettlementWeight::set(Weight::from_parts(WEIGHT_REF_TIME_PER_SECOND / 4, u64::MAX));
+		run_to_block(5);
+		assert_eq!(AuctionSettlementCursor::<TestRuntime>::get(), Some(6));
+	});
+}
+
+#[test]
+fn failed_settlement_releases_the_bid() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		let game_id = create_game_for(ALICE);
+		assert_ok!(PalletGames::create_auction(RuntimeOrigin::signed(ALICE), game_id, 10, 3));
+		assert_ok!(PalletGames::bid(RuntimeOrigin::signed(BOB), game_id, 20));
+		// The winning bid is slashed elsewhere, so the seller cannot be paid.
+		assert_ok!(PalletBalances::burn_held(
+			&HoldReason::AuctionBid.into(),
+			&BOB,
+			15,
+			Precision::Exact,
+			Fortitude::Force
+		));
+
+		run_to_block(3);
+
+		assert_eq!(Games::<TestRuntime>::get(game_id).unwrap().owner, ALICE);
+		assert!(!Auctions::<TestRuntime>::contains_key(game_id));
+		assert_eq!(PalletBalances::total_balance_on_hold(&BOB), 0);
+		assert_eq!(PalletBalances::balance(&BOB), 85);
+		System::assert_last_event(Event::<TestRuntime>::AuctionEnded { id: game_id }.into());
+	});
+}
+
+#[test]
+fn dutch_auction_price_falls_to_the_floor() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		let game_id = create_game_for(ALICE);
+		assert_noop!(
+			PalletGames::create_dutch_auction(RuntimeOrigin::signed(ALICE), game_id, 10, 20, 5),
+			Error::<TestRuntime>::InvalidPriceSchedule
+		);
+		assert_noop!(
+			PalletGames::create_dutch_auction(RuntimeOrigin::signed(ALICE), game_id, 100, 20, 0),
+			Error::<TestRuntime>::InvalidPriceSchedule
+		);
+		assert_ok!(PalletGames::create_dutch_auction(
+			RuntimeOrigin::signed(ALICE),
+			game_id,
+			100,
+			20,
+			8
+		));
+
+		assert_eq!(PalletGames::current_price(game_id), Some(100));
+		System::set_block_number(3);
+		assert_eq!(PalletGames::current_price(game_id), Some(80));
+		System::set_block_number(9);
+		assert_eq!(PalletGames::current_price(game_id), Some(20));
+		System::set_block_number(50);
+		assert_eq!(PalletGames::current_price(game_id), Some(20));
+	});
+}
+
+#[test]
+fn dutch_auction_sells_to_first_buyer_at_current_price() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&ALICE, 100));
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		assert_ok!(PalletBalances::mint_into(&CHARLIE, 100));
+		let game_id = create_game_for(ALICE);
+		assert_ok!(PalletGames::set_game_price(
+			RuntimeOrigin::signed(ALICE),
+			game_id,
+			Some(90),
+			None
+		));
+		assert_ok!(PalletGames::create_dutch_auction(
+			RuntimeOrigin::signed(ALICE),
+			game_id,
+			60,
+			20,
+			4
+		));
+		assert_eq!(Games::<TestRuntime>::get(game_id).unwrap().value, None);
+		assert_noop!(
+			PalletGames::transfer_game(RuntimeOrigin::signed(ALICE), BOB, game_id),
+			Error::<TestRuntime>::GameLocked
+		);
+
+		System::set_block_number(3);
+		assert_noop!(
+			PalletGames::buy_game(RuntimeOrigin::signed(BOB), game_id, 39),
+			Error::<TestRuntime>::PriceTooLow
+		);
+		assert_ok!(PalletGames::buy_game(RuntimeOrigin::signed(BOB), game_id, 45));
+
+		assert_eq!(Games::<TestRuntime>::get(game_id).unwrap().owner, BOB);
+		assert!(!DutchAuctions::<TestRuntime>::contains_key(game_id));
+		assert_eq!(PalletBalances::balance(&ALICE), 140);
+		assert_eq!(PalletBalances::balance(&BOB), 60);
+		System::assert_last_event(
+			Event::<TestRuntime>::GameSold { buyer: BOB, id: game_id, price: 40 }.into(),
+		);
+		assert_noop!(
+			PalletGames::buy_game(RuntimeOrigin::signed(CHARLIE), game_id, 100),
+			Error::<TestRuntime>::NotForSale
+		);
+	});
+}
+
+#[test]
+fn cancel_dutch_auction_unlists_the_game() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		let game_id = create_game_for(ALICE);
+		assert_ok!(PalletGames::create_dutch_auction(
+			RuntimeOrigin::signed(ALICE),
+			game_id,
+			60,
+			20,
+			4
+		));
+		assert_noop!(
+			PalletGames::create_auction(RuntimeOrigin::signed(ALICE), game_id, 10, 5),
+			Error::<TestRuntime>::GameLocked
+		);
+		assert_noop!(
+			PalletGames::cancel_auction(RuntimeOrigin::signed(BOB), game_id),
+			Error::<TestRuntime>::NotAuthorized
+		);
+
+		assert_ok!(PalletGames::cancel_auction(RuntimeOrigin::signed(ALICE), game_id));
+
+		assert_eq!(PalletGames::current_price(game_id), None);
+		assert_noop!(
+			PalletGames::buy_game(RuntimeOrigin::signed(BOB), game_id, 100),
+			Error::<TestRuntime>::NotForSale
+		);
+		assert_ok!(PalletGames::transfer_game(RuntimeOrigin::signed(ALICE), BOB, game_id));
+	});
+}
+
+#[test]
+fn accepted_offer_pays_from_the_hold() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&ALICE, 100));
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		assert_ok!(PalletBalances::mint_into(&CHARLIE, 100));
+		let game_id = create_game_for(ALICE);
+
+		assert_ok!(PalletGames::make_offer(RuntimeOrigin::signed(BOB), game_id, 30, 10));
+		assert_ok!(PalletGames::make_offer(RuntimeOrigin::signed(CHARLIE), game_id, 20, 10));
+		assert_eq!(PalletBalances::total_balance_on_hold(&BOB), 30);
+		assert_noop!(
+			PalletGames::accept_offer(RuntimeOrigin::signed(BOB), game_id, BOB),
+			Error::<TestRuntime>::NotAuthorized
+		);
+		assert_noop!(
+			PalletGames::accept_offer(RuntimeOrigin::signed(ALICE), game_id, DAVE),
+			Error::<TestRuntime>::OfferNotFound
+		);
+
+		assert_ok!(PalletGames::accept_offer(RuntimeOrigin::signed(ALICE), game_id, BOB));
+
+		assert_eq!(Games::<TestRuntime>::get(game_id).unwrap().owner, BOB);
+		assert_eq!(PalletBalances::balance(&ALICE), 130);
+		assert_eq!(PalletBalances::total_balance(&BOB), 70);
+		// The losing offer is released once the game changes hands.
+		assert_eq!(PalletBalances::total_balance_on_hold(&CHARLIE), 0);
+		assert!(!Offers::<TestRuntime>::contains_key(game_id));
+		assert!(OffersExpiringAt::<TestRuntime>::get(10).is_empty());
+		System::assert_has_event(
+			Event::<TestRuntime>::OfferReleased { buyer: CHARLIE, id: game_id }.into(),
+		);
+		System::assert_last_event(
+			Event::<TestRuntime>::OfferAccepted { buyer: BOB, id: game_id, amount: 30 }.into(),
+		);
+	});
+}
+
+#[test]
+fn offers_are_validated() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		assert_ok!(PalletBalances::mint_into(&CHARLIE, 100));
+		assert_ok!(PalletBalances::mint_into(&DAVE, 10));
+		let game_id = create_game_for(ALICE);
+
+		assert_noop!(
+			PalletGames::make_offer(RuntimeOrigin::signed(BOB), [1u8; 32], 30, 10),
+			Error::<TestRuntime>::GameNotFound
+		);
+		assert_noop!(
+			PalletGames::make_offer(RuntimeOrigin::signed(ALICE), game_id, 30, 10),
+			Error::<TestRuntime>::TransferToSelf
+		);
+		assert_noop!(
+			PalletGames::make_offer(RuntimeOrigin::signed(BOB), game_id, 30, 1),
+			Error::<TestRuntime>::OfferExpiryInPast
+		);
+		assert_noop!(
+			PalletGames::make_offer(RuntimeOrigin::signed(BOB), game_id, 30, 102),
+			Error::<TestRuntime>::OfferExpiryTooFar
+		);
+		assert_noop!(
+			PalletGames::make_offer(RuntimeOrigin::signed(BOB), game_id, 4, 10),
+			Error::<TestRuntime>::OfferTooLow
+		);
+		assert_noop!(
+			PalletGames::make_offer(RuntimeOrigin::signed(DAVE), game_id, 30, 10),
+			TokenError::FundsUnavailable
+		);
+		assert_ok!(PalletGames::make_offer(RuntimeOrigin::signed(BOB), game_id, 30, 10));
+		assert_noop!(
+			PalletGames::make_offer(RuntimeOrigin::signed(BOB), game_id, 40, 10),
+			Error::<TestRuntime>::OfferExists
+		);
+		assert_ok!(PalletGames::make_offer(RuntimeOrigin::signed(CHARLIE), game_id, 30, 10));
+		assert_noop!(
+			PalletGames::make_offer(RuntimeOrigin::signed(DAVE), game_id, 5, 10),
+			Error::<TestRuntime>::TooManyOffers
+		);
+
+		let other_game = create_game_for(ALICE);
+		assert_ok!(PalletGames::make_offer(RuntimeOrigin::signed(BOB), other_game, 5, 10));
+		assert_noop!(
+			PalletGames::make_offer(RuntimeOrigin::signed(DAVE), other_game, 5, 10),
+			Error::<TestRuntime>::TooManyOffersExpiring
+		);
+		assert_ok!(PalletGames::make_offer(RuntimeOrigin::signed(DAVE), other_game, 5, 11));
+	});
+}
+
+#[test]
+fn a_higher_offer_outbids_the_lowest_once_a_game_is_full() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		for buyer in [BOB, CHARLIE, DAVE] {
+			assert_ok!(PalletBalances::mint_into(&buyer, 100));
+		}
+		let game_id = create_game_for(ALICE);
+		assert_ok!(PalletGames::make_offer(RuntimeOrigin::signed(BOB), game_id, 5, 10));
+		assert_ok!(PalletGames::make_offer(RuntimeOrigin::signed(CHARLIE), game_id, 20, 10));
+
+		// Matching the lowest offer is not enough.
+		assert_noop!(
+			PalletGames::make_offer(RuntimeOrigin::signed(DAVE), game_id, 5, 10),
+			Error::<TestRuntime>::TooManyOffers
+		);
+		assert_ok!(PalletGames::make_offer(RuntimeOrigin::signed(DAVE), game_id, 6, 10));
+
+		let buyers: Vec<_> =
+			Offers::<TestRuntime>::get(game_id).iter().map(|offer| offer.buyer).collect();
+		assert_eq!(buyers, vec![CHARLIE, DAVE]);
+		assert_eq!(PalletBalances::total_balance_on_hold(&BOB), 0);
+		assert_eq!(PalletBalances::total_balance_on_hold(&DAVE), 6);
+		assert_eq!(
+			OffersExpiringAt::<TestRuntime>::get(10).into_inner(),
+			vec![(game_id, CHARLIE), (game_id, DAVE)]
+		);
+		System::assert_has_event(
+			Event::<TestRuntime>::OfferReleased { buyer: BOB, id: game_id }.into(),
+		);
+	});
+}
+
+#[test]
+fn offers_can_be_made_on_unlisted_games_and_withdrawn() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		let game_id = create_game_for(ALICE);
+		assert_eq!(Games::<TestRuntime>::get(game_id).unwrap().value, None);
+
+		assert_ok!(PalletGames::make_offer(RuntimeOrigin::signed(BOB), game_id, 30, 10));
+		assert_noop!(
+			PalletGames::withdraw_offer(RuntimeOrigin::signed(CHARLIE), game_id),
+			Error::<TestRuntime>::OfferNotFound
+		);
+		assert_ok!(PalletGames::withdraw_offer(RuntimeOrigin::signed(BOB), game_id));
+
+		assert_eq!(PalletBalances::balance(&BOB), 100);
+		assert!(!Offers::<TestRuntime>::contains_key(game_id));
+		assert!(OffersExpiringAt::<TestRuntime>::get(10).is_empty());
+		System::assert_last_event(
+			Event::<TestRuntime>::OfferReleased { buyer: BOB, id: game_id }.into(),
+		);
+	});
+}
+
+#[test]
+fn expired_offers_are_released() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		let game_id = create_game_for(ALICE);
+		assert_ok!(PalletGames::make_offer(RuntimeOrigin::signed(BOB), game_id, 30, 4));
+
+		run_to_block(3);
+		assert_eq!(PalletBalances::total_balance_on_hold(&BOB), 30);
+		run_to_block(4);
+
+		assert_eq!(PalletBalances::total_balance_on_hold(&BOB), 0);
+		assert!(!Offers::<TestRuntime>::contains_key(game_id));
+		assert!(!OffersExpiringAt::<TestRuntime>::contains_key(4));
+		System::assert_last_event(
+			Event::<TestRuntime>::OfferReleased { buyer: BOB, id: game_id }.into(),
+		);
+		assert_noop!(
+			PalletGames::accept_offer(RuntimeOrigin::signed(ALICE), game_id, BOB),
+			Error::<TestRuntime>::OfferNotFound
+		);
+	});
+}
+
+#[test]
+fn transfers_release_open_offers() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		assert_ok!(PalletBalances::mint_into(&CHARLIE, 100));
+		let game_id = create_game_for(ALICE);
+		assert_ok!(PalletGames::make_offer(RuntimeOrigin::signed(BOB), game_id, 30, 10));
+		assert_ok!(PalletGames::make_offer(RuntimeOrigin::signed(CHARLIE), game_id, 20, 12));
+
+		assert_ok!(PalletGames::transfer_game(RuntimeOrigin::signed(ALICE), DAVE, game_id));
+
+		assert_eq!(PalletBalances::total_balance_on_hold(&BOB), 0);
+		assert_eq!(PalletBalances::total_balance_on_hold(&CHARLIE), 0);
+		assert!(!Offers::<TestRuntime>::contains_key(game_id));
+		assert!(OffersExpiringAt::<TestRuntime>::get(10).is_empty());
+		assert!(OffersExpiringAt::<TestRuntime>::get(12).is_empty());
+	});
+}
+
+#[test]
+fn resales_pay_the_creator_royalty() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&ALICE, 100));
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		assert_ok!(PalletBalances::mint_into(&CHARLIE, 100));
+		assert_noop!(
+			PalletGames::create_game(RuntimeOrigin::signed(ALICE), Permill::from_percent(26)),
+			Error::<TestRuntime>::RoyaltyTooHigh
+		);
+		let game_id = PalletGames::gen_game_key();
+		assert_ok!(PalletGames::create_game(
+			RuntimeOrigin::signed(ALICE),
+			Permill::from_percent(10)
+		));
+
+		// The creator selling their own game keeps the whole price.
+		assert_ok!(PalletGames::set_game_price(
+			RuntimeOrigin::signed(ALICE),
+			game_id,
+			Some(50),
+			None
+		));
+		assert_ok!(PalletGames::buy_game(RuntimeOrigin::signed(BOB), game_id, 50));
+		assert_eq!(PalletBalances::balance(&ALICE), 150);
+		System::assert_has_event(
+			Event::<TestRuntime>::ProceedsPaid {
+				id: game_id,
+				gross: 50,
+				fee: 0,
+				royalty: 0,
+				net: 50,
+			}
+			.into(),
+		);
+
+		assert_ok!(PalletGames::set_game_price(
+			RuntimeOrigin::signed(BOB),
+			game_id,
+			Some(45),
+			None
+		));
+		assert_ok!(PalletGames::buy_game(RuntimeOrigin::signed(CHARLIE), game_id, 45));
+
+		let game = Games::<TestRuntime>::get(game_id).unwrap();
+		assert_eq!((game.owner, game.creator), (CHARLIE, ALICE));
+		assert_eq!(PalletBalances::balance(&ALICE), 154);
+		assert_eq!(PalletBalances::balance(&BOB), 91);
+		assert_eq!(PalletBalances::balance(&CHARLIE), 55);
+		System::assert_has_event(
+			Event::<TestRuntime>::ProceedsPaid {
+				id: game_id,
+				gross: 45,
+				fee: 0,
+				royalty: 4,
+				net: 41,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn auction_and_offer_settlements_pay_the_creator_royalty() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&ALICE, 100));
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		assert_ok!(PalletBalances::mint_into(&CHARLIE, 100));
+		assert_ok!(PalletBalances::mint_into(&DAVE, 100));
+		let game_id = PalletGames::gen_game_key();
+		assert_ok!(PalletGames::create_game(
+			RuntimeOrigin::signed(ALICE),
+			Permill::from_percent(20)
+		));
+		assert_ok!(PalletGames::transfer_game(RuntimeOrigin::signed(ALICE), BOB, game_id));
+
+		assert_ok!(PalletGames::create_auction(RuntimeOrigin::signed(BOB), game_id, 10, 3));
+		assert_ok!(PalletGames::bid(RuntimeOrigin::signed(CHARLIE), game_id, 30));
+		run_to_block(3);
+
+		assert_eq!(Games::<TestRuntime>::get(game_id).unwrap().owner, CHARLIE);
+		assert_eq!(PalletBalances::balance(&ALICE), 106);
+		assert_eq!(PalletBalances::balance(&BOB), 124);
+		assert_eq!(PalletBalances::total_balance(&CHARLIE), 70);
+
+		assert_ok!(PalletGames::make_offer(RuntimeOrigin::signed(DAVE), game_id, 40, 10));
+		assert_ok!(PalletGames::accept_offer(RuntimeOrigin::signed(CHARLIE), game_id, DAVE));
+
+		assert_eq!(Games::<TestRuntime>::get(game_id).unwrap().owner, DAVE);
+		assert_eq!(PalletBalances::balance(&ALICE), 114);
+		assert_eq!(PalletBalances::balance(&CHARLIE), 102);
+		assert_eq!(PalletBalances::total_balance(&DAVE), 60);
+		System::assert_has_event(
+			Event::<TestRuntime>::ProceedsPaid {
+				id: game_id,
+				gross: 40,
+				fee: 0,
+				royalty: 8,
+				net: 32,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn migration_to_v1_credits_owners_as_creators() {
+	use frame::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<PalletGames>();
+		let id = [7u8; 32];
+		// The v0 layout: key data, owner and price.
+		frame_support::storage::unhashed::put(
+			&Games::<TestRuntime>::hashed_key_for(id),
+			&(id, ALICE, Some(5u64)),
+		);
+
+		crate::migrations::v1::MigrateV0ToV1::<TestRuntime>::on_runtime_upgrade();
+
+		let game = crate::migrations::v1::Games::<TestRuntime>::get(id).unwrap();
+		assert_eq!((game.owner, game.creator), (ALICE, ALICE));
+		assert_eq!(game.value, Some(5));
+		assert_eq!(game.royalty, Permill::zero());
+		assert_eq!(PalletGames::on_chain_storage_version(), 1);
+	});
+}
+
+#[test]
+fn migration_to_v2_counts_live_games() {
+	use frame::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		create_game_for(ALICE);
+		create_game_for(BOB);
+		LiveGameCount::<TestRuntime>::kill();
+		StorageVersion::new(1).put::<PalletGames>();
+
+		crate::migrations::v2::MigrateV1ToV2::<TestRuntime>::on_runtime_upgrade();
+
+		assert_eq!(LiveGameCount::<TestRuntime>::get(), 2);
+		assert_eq!(PalletGames::on_chain_storage_version(), 2);
+	});
+}
+
+#[test]
+fn migration_to_v3_indexes_every_owned_game() {
+	use frame::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(2).put::<PalletGames>();
+		let (first, second, third) = ([1u8; 32], [2u8; 32], [3u8; 32]);
+		crate::migrations::v3::GamesOwnedBy::<TestRuntime>::insert(
+			ALICE,
+			BoundedVec::truncate_from(vec![first, second]),
+		);
+		crate::migrations::v3::GamesOwnedBy::<TestRuntime>::insert(
+			BOB,
+			BoundedVec::truncate_from(vec![third]),
+		);
+		crate::migrations::v3::GamesOwnedBy::<TestRuntime>::insert(CHARLIE, BoundedVec::new());
+
+		crate::migrations::v3::MigrateV2ToV3::<TestRuntime>::on_runtime_upgrade();
+
+		let mut alice = owned_by(ALICE);
+		alice.sort();
+		assert_eq!(alice, vec![first, second]);
+		assert_eq!(owned_by(BOB), vec![third]);
+		assert_eq!(GamesOwnedBy::<TestRuntime>::iter_keys().count(), 3);
+		assert_eq!(PalletGames::on_chain_storage_version(), 3);
+	});
+}
+
+#[test]
+fn migration_to_v4_leaves_every_game_unrented() {
+	use crate::migrations::v1::GameV1;
+	use frame::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(3).put::<PalletGames>();
+		let id = [7u8; 32];
+		crate::migrations::v1::Games::<TestRuntime>::insert(
+			id,
+			GameV1 {
+				key_data: id,
+				owner: BOB,
+				creator: ALICE,
+				value: Some(5),
+				royalty: Permill::from_percent(10),
+			},
+		);
+
+		crate::migrations::v4::MigrateV3ToV4::<TestRuntime>::on_runtime_upgrade();
+
+		let game = crate::migrations::v4::Games::<TestRuntime>::get(id).unwrap();
+		assert_eq!((game.owner, game.creator, game.value), (BOB, ALICE, Some(5)));
+		assert_eq!(game.royalty, Permill::from_percent(10));
+		assert!(game.rental.is_none());
+		assert_eq!(PalletGames::on_chain_storage_version(), 4);
+	});
+}
+
+#[test]
+fn migration_to_v5_keeps_every_game_transferable() {
+	use crate::migrations::v4::GameV4;
+	use frame::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(4).put::<PalletGames>();
+		let id = [7u8; 32];
+		crate::migrations::v4::Games::<TestRuntime>::insert(
+			id,
+			GameV4 {
+				key_data: id,
+				owner: BOB,
+				creator: ALICE,
+				value: None,
+				royalty: Permill::from_percent(10),
+				rental: Some(Rental { renter: CHARLIE, until: 9 }),
+			},
+		);
+
+		crate::migrations::v5::MigrateV4ToV5::<TestRuntime>::on_runtime_upgrade();
+
+		let game = Games::<TestRuntime>::get(id).unwrap();
+		assert_eq!((game.owner, game.creator), (BOB, ALICE));
+		assert_eq!(game.rental.map(|rental| (rental.renter, rental.until)), Some((CHARLIE, 9)));
+		assert!(game.transferable);
+		assert_eq!(PalletGames::on_chain_storage_version(), 5);
+	});
+}
+
+#[test]
+fn sales_pay_the_protocol_fee() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		assert_ok!(PalletBalances::mint_into(&CHARLIE, 100));
+		assert_noop!(
+			PalletGames::set_protocol_fee(RuntimeOrigin::signed(ALICE), Permill::from_percent(5)),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(PalletGames::set_protocol_fee(RuntimeOrigin::root(), Permill::from_percent(5)));
+		System::assert_last_event(
+			Event::<TestRuntime>::ProtocolFeeSet { fee: Permill::from_percent(5) }.into(),
+		);
+
+		let game_id = PalletGames::gen_game_key();
+		assert_ok!(PalletGames::create_game(
+			RuntimeOrigin::signed(ALICE),
+			Permill::from_percent(10)
+		));
+		assert_ok!(PalletGames::transfer_game(RuntimeOrigin::signed(ALICE), BOB, game_id));
+		assert_ok!(PalletGames::set_game_price(
+			RuntimeOrigin::signed(BOB),
+			game_id,
+			Some(60),
+			None
+		));
+		assert_ok!(PalletGames::buy_game(RuntimeOrigin::signed(CHARLIE), game_id, 60));
+
+		assert_eq!(PalletBalances::balance(&TREASURY), 3);
+		assert_eq!(PalletBalances::balance(&ALICE), 6);
+		assert_eq!(PalletBalances::balance(&BOB), 151);
+		assert_eq!(PalletBalances::balance(&CHARLIE), 40);
+		System::assert_has_event(
+			Event::<TestRuntime>::ProceedsPaid {
+				id: game_id,
+				gross: 60,
+				fee: 3,
+				royalty: 6,
+				net: 51,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn protocol_fee_leaves_room_for_the_largest_royalty() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&CHARLIE, 100));
+		assert_ok!(PalletBalances::mint_into(&TREASURY, 1));
+		assert_noop!(
+			PalletGames::set_protocol_fee(RuntimeOrigin::root(), Permill::from_percent(76)),
+			Error::<TestRuntime>::FeeTooHigh
+		);
+		assert_ok!(PalletGames::set_protocol_fee(RuntimeOrigin::root(), Permill::from_percent(75)));
+
+		let game_id = PalletGames::gen_game_key();
+		assert_ok!(PalletGames::create_game(
+			RuntimeOrigin::signed(ALICE),
+			Permill::from_percent(25)
+		));
+		assert_ok!(PalletGames::transfer_game(RuntimeOrigin::signed(ALICE), BOB, game_id));
+		assert_ok!(PalletGames::set_game_price(
+			RuntimeOrigin::signed(BOB),
+			game_id,
+			Some(40),
+			None
+		));
+		assert_ok!(PalletGames::buy_game(RuntimeOrigin::signed(CHARLIE), game_id, 40));
+
+		assert_eq!(PalletBalances::balance(&CHARLIE), 60);
+		System::assert_has_event(
+			Event::<TestRuntime>::ProceedsPaid {
+				id: game_id,
+				gross: 40,
+				fee: 30,
+				royalty: 10,
+				net: 0,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn fees_the_fee_account_cannot_receive_stay_with_the_seller() {
+	build_and_execute(|| {
+		ExistentialDeposit::set(10);
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		assert_ok!(PalletBalances::mint_into(&CHARLIE, 100));
+		assert_ok!(PalletGames::set_protocol_fee(RuntimeOrigin::root(), Permill::from_percent(5)));
+		let game_id = create_game_for(ALICE);
+		assert_ok!(PalletGames::set_game_price(
+			RuntimeOrigin::signed(ALICE),
+			game_id,
+			Some(60),
+			None
+		));
+
+		// The 3 unit fee is below the existential deposit of the missing fee account.
+		assert_ok!(PalletGames::buy_game(RuntimeOrigin::signed(BOB), game_id, 60));
+
+		assert_eq!(PalletBalances::balance(&TREASURY), 0);
+		assert_eq!(PalletBalances::balance(&ALICE), 60);
+		System::assert_has_event(
+			Event::<TestRuntime>::ProceedsPaid {
+				id: game_id,
+				gross: 60,
+				fee: 0,
+				royalty: 0,
+				net: 60,
+			}
+			.into(),
+		);
+
+		// Once the fee account exists, it takes the fee again.
+		assert_ok!(PalletBalances::mint_into(&TREASURY, 10));
+		assert_ok!(PalletGames::set_game_price(
+			RuntimeOrigin::signed(BOB),
+			game_id,
+			Some(60),
+			None
+		));
+		assert_ok!(PalletGames::buy_game(RuntimeOrigin::signed(CHARLIE), game_id, 60));
+		assert_eq!(PalletBalances::balance(&TREASURY), 13);
+	});
+}
+
+#[test]
+fn royalties_a_dead_creator_cannot_receive_stay_with_the_seller() {
+	build_and_execute(|| {
+		ExistentialDeposit::set(10);
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		let game_id = PalletGames::gen_game_key();
+		assert_ok!(PalletGames::create_game(
+			RuntimeOrigin::signed(ALICE),
+			Permill::from_percent(10)
+		));
+		assert_ok!(PalletGames::transfer_game(RuntimeOrigin::signed(ALICE), CHARLIE, game_id));
+		assert_ok!(PalletGames::set_game_price(
+			RuntimeOrigin::signed(CHARLIE),
+			game_id,
+			Some(50),
+			None
+		));
+
+		// The 5 unit royalty is below the existential deposit of the creator's missing account.
+		assert_ok!(PalletGames::buy_game(RuntimeOrigin::signed(BOB), game_id, 50));
+
+		assert_eq!(PalletBalances::balance(&ALICE), 0);
+		assert_eq!(PalletBalances::balance(&CHARLIE), 50);
+		System::assert_has_event(
+			Event::<TestRuntime>::ProceedsPaid {
+				id: game_id,
+				gross: 50,
+				fee: 0,
+				royalty: 0,
+				net: 50,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn proceeds_a_dead_seller_cannot_receive_go_to_the_fee_account_or_back_to_the_buyer() {
+	build_and_execute(|| {
+		ExistentialDeposit::set(10);
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		let game_id = create_game_for(ALICE);
+
+		// Neither the seller nor the fee account can receive 8 units, so the buyer keeps them.
+		assert_ok!(PalletGames::make_offer(RuntimeOrigin::signed(BOB), game_id, 8, 50));
+		assert_ok!(PalletGames::accept_offer(RuntimeOrigin::signed(ALICE), game_id, BOB));
+
+		assert_eq!(owned_by(BOB), vec![game_id]);
+		assert_eq!(PalletBalances::balance(&ALICE), 0);
+		assert_eq!(PalletBalances::balance(&BOB), 100);
+		assert_eq!(PalletBalances::total_balance_on_hold(&BOB), 0);
+		System::assert_has_event(
+			Event::<TestRuntime>::ProceedsPaid {
+				id: game_id,
+				gross: 8,
+				fee: 0,
+				royalty: 0,
+				net: 0,
+			}
+			.into(),
+		);
+
+		// Once the fee account exists, it takes what the seller cannot.
+		assert_ok!(PalletBalances::mint_into(&TREASURY, 10));
+		assert_ok!(PalletBalances::mint_into(&CHARLIE, 100));
+		assert_ok!(PalletGames::transfer_game(RuntimeOrigin::signed(BOB), ALICE, game_id));
+		assert_ok!(PalletGames::make_offer(RuntimeOrigin::signed(CHARLIE), game_id, 8, 50));
+		assert_ok!(PalletGames::accept_offer(RuntimeOrigin::signed(ALICE), game_id, CHARLIE));
+
+		assert_eq!(PalletBalances::balance(&ALICE), 0);
+		assert_eq!(PalletBalances::balance(&TREASURY), 18);
+		assert_eq!(PalletBalances::balance(&CHARLIE), 92);
+		System::assert_has_event(
+			Event::<TestRuntime>::ProceedsPaid {
+				id: game_id,
+				gross: 8,
+				fee: 8,
+				royalty: 0,
+				net: 0,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn metadata_is_backed_by_a_refundable_deposit() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&ALICE, 100));
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		let game_id = create_game_for(ALICE);
+		assert_ok!(PalletGames::transfer_game(RuntimeOrigin::signed(ALICE), BOB, game_id));
+		let attributes: Attributes<TestRuntime> =
+			vec![(bounded("genre"), bounded("board"))].try_into().unwrap();
+
+		assert_noop!(
+			PalletGames::set_metadata(
+				RuntimeOrigin::signed(CHARLIE),
+				game_id,
+				bounded("Chess"),
+				bounded("ipfs://x"),
+				attributes.clone()
+			),
+			Error::<TestRuntime>::NotAuthorized
+		);
+		assert_ok!(PalletGames::set_metadata(
+			RuntimeOrigin::signed(BOB),
+			game_id,
+			bounded("Chess"),
+			bounded("ipfs://x"),
+			attributes.clone()
+		));
+		// A base deposit of 10 plus 28 encoded bytes.
+		assert_eq!(PalletBalances::total_balance_on_hold(&BOB), 38);
+		System::assert_last_event(
+			Event::<TestRuntime>::MetadataSet { id: game_id, deposit: 38 }.into(),
+		);
+
+		// The creator may replace it, and then pays for it instead.
+		assert_ok!(PalletGames::set_metadata(
+			RuntimeOrigin::signed(ALICE),
+			game_id,
+			bounded("Go"),
+			bounded("ipfs://y"),
+			Default::default()
+		));
+		assert_eq!(PalletBalances::total_balance_on_hold(&BOB), 0);
+		assert_eq!(PalletBalances::total_balance_on_hold(&ALICE), 23);
+		let metadata = GameMetadata::<TestRuntime>::get(game_id).unwrap();
+		assert_eq!((metadata.depositor, metadata.name), (ALICE, bounded("Go")));
+
+		assert_ok!(PalletGames::clear_metadata(RuntimeOrigin::signed(BOB), game_id));
+		assert_eq!(PalletBalances::total_balance_on_hold(&ALICE), 0);
+		assert!(!GameMetadata::<TestRuntime>::contains_key(game_id));
+		assert_noop!(
+			PalletGames::clear_metadata(RuntimeOrigin::signed(BOB), game_id),
+			Error::<TestRuntime>::MetadataNotFound
+		);
+	});
+}
+
+#[test]
+fn metadata_deposits_are_refunded_when_the_depositor_parts_with_the_game() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		let game_id = create_game_for(ALICE);
+		assert_ok!(PalletGames::transfer_game(RuntimeOrigin::signed(ALICE), BOB, game_id));
+		assert_ok!(PalletGames::set_metadata(
+			RuntimeOrigin::signed(BOB),
+			game_id,
+			bounded("Chess"),
+			bounded("ipfs://x"),
+			Default::default()
+		));
+		assert_eq!(PalletBalances::total_balance_on_hold(&BOB), 26);
+		assert_ok!(PalletGames::transfer_game(RuntimeOrigin::signed(BOB), CHARLIE, game_id));
+
+		// Bob gets the deposit back, but the metadata the buyer saw stays, and only the new owner
+		// or the creator may clear it.
+		assert_eq!(PalletBalances::total_balance_on_hold(&BOB), 0);
+		assert_eq!(PalletBalances::free_balance(BOB), 100);
+		let metadata = GameMetadata::<TestRuntime>::get(game_id).unwrap();
+		assert_eq!((metadata.deposit, metadata.name), (0, bounded("Chess")));
+		assert_noop!(
+			PalletGames::clear_metadata(RuntimeOrigin::signed(BOB), game_id),
+			Error::<TestRuntime>::NotAuthorized
+		);
+		assert_ok!(PalletGames::clear_metadata(RuntimeOrigin::signed(CHARLIE), game_id));
+		System::assert_last_event(Event::<TestRuntime>::MetadataCleared { id: game_id }.into());
+		assert!(!GameMetadata::<TestRuntime>::contains_key(game_id));
+	});
+}
+
+#[test]
+fn locked_metadata_cannot_change() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&ALICE, 100));
+		let game_id = create_game_for(ALICE);
+		assert_noop!(
+			PalletGames::lock_metadata(RuntimeOrigin::signed(ALICE), game_id),
+			Error::<TestRuntime>::MetadataNotFound
+		);
+		assert_ok!(PalletGames::set_metadata(
+			RuntimeOrigin::signed(ALICE),
+			game_id,
+			bounded("Chess"),
+			bounded("ipfs://x"),
+			Default::default()
+		));
+		assert_noop!(
+			PalletGames::lock_metadata(RuntimeOrigin::signed(BOB), game_id),
+			Error::<TestRuntime>::NotAuthorized
+		);
+
+		assert_ok!(PalletGames::lock_metadata(RuntimeOrigin::signed(ALICE), game_id));
+
+		assert_noop!(
+			PalletGames::set_metadata(
+				RuntimeOrigin::signed(ALICE),
+				game_id,
+				bounded("Go"),
+				bounded("ipfs://y"),
+				Default::default()
+			),
+			Error::<TestRuntime>::MetadataIsLocked
+		);
+		assert_noop!(
+			PalletGames::clear_metadata(RuntimeOrigin::signed(ALICE), game_id),
+			Error::<TestRuntime>::MetadataIsLocked
+		);
+		assert!(GameMetadata::<TestRuntime>::get(game_id).unwrap().locked);
+	});
+}
+
+#[test]
+fn approved_account_can_move_the_game_once() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		let game_id = create_game_for(ALICE);
+		assert_noop!(
+			PalletGames::transfer_from(RuntimeOrigin::signed(BOB), ALICE, CHARLIE, game_id),
+			Error::<TestRuntime>::NotAuthorized
+		);
+		assert_noop!(
+			PalletGames::approve(RuntimeOrigin::signed(BOB), game_id, Some(BOB)),
+			Error::<TestRuntime>::NotAuthorized
+		);
+
+		assert_ok!(PalletGames::approve(RuntimeOrigin::signed(ALICE), game_id, Some(BOB)));
+		System::assert_last_event(
+			Event::<TestRuntime>::Approval { owner: ALICE, delegate: Some(BOB), id: game_id }
+				.into(),
+		);
+		assert_ok!(PalletGames::transfer_from(RuntimeOrigin::signed(BOB), ALICE, CHARLIE, game_id));
+
+		assert_eq!(Games::<TestRuntime>::get(game_id).unwrap().owner, CHARLIE);
+		assert!(!Approvals::<TestRuntime>::contains_key(game_id));
+		assert_noop!(
+			PalletGames::transfer_from(RuntimeOrigin::signed(BOB), CHARLIE, BOB, game_id),
+			Error::<TestRuntime>::NotAuthorized
+		);
+	});
+}
+
+#[test]
+fn operators_can_move_and_approve_every_game() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		let first = create_game_for(ALICE);
+		let second = create_game_for(ALICE);
+		assert_noop!(
+			PalletGames::set_operator(RuntimeOrigin::signed(ALICE), ALICE, true),
+			Error::<TestRuntime>::TransferToSelf
+		);
+
+		assert_ok!(PalletGames::set_operator(RuntimeOrigin::signed(ALICE), BOB, true));
+		assert_ok!(PalletGames::transfer_from(RuntimeOrigin::signed(BOB), ALICE, CHARLIE, first));
+		assert_ok!(PalletGames::approve(RuntimeOrigin::signed(BOB), second, Some(DAVE)));
+		assert_eq!(Approvals::<TestRuntime>::get(second), Some(DAVE));
+
+		assert_ok!(PalletGames::set_operator(RuntimeOrigin::signed(ALICE), BOB, false));
+		System::assert_last_event(
+			Event::<TestRuntime>::OperatorSet { owner: ALICE, operator: BOB, approved: false }
+				.into(),
+		);
+		assert_noop!(
+			PalletGames::transfer_from(RuntimeOrigin::signed(BOB), ALICE, CHARLIE, second),
+			Error::<TestRuntime>::NotAuthorized
+		);
+		assert_ok!(PalletGames::transfer_from(RuntimeOrigin::signed(DAVE), ALICE, DAVE, second));
+	});
+}
+
+#[test]
+fn approvals_are_cleared_when_the_game_is_sold() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&CHARLIE, 100));
+		let game_id = create_game_for(ALICE);
+		assert_ok!(PalletGames::approve(RuntimeOrigin::signed(ALICE), game_id, Some(BOB)));
+		assert_ok!(PalletGames::set_game_price(
+			RuntimeOrigin::signed(ALICE),
+			game_id,
+			Some(10),
+			None
+		));
+
+		assert_ok!(PalletGames::buy_game(RuntimeOrigin::signed(CHARLIE), game_id, 10));
+
+		assert!(!Approvals::<TestRuntime>::contains_key(game_id));
+		assert_noop!(
+			PalletGames::transfer_from(RuntimeOrigin::signed(BOB), CHARLIE, BOB, game_id),
+			Error::<TestRuntime>::NotAuthorized
+		);
+	});
+}
+
+#[test]
+fn burning_removes_the_game_but_not_the_mint_count() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		let game_id = create_game_for(ALICE);
+		let kept = create_game_for(ALICE);
+		assert_noop!(
+			PalletGames::burn_game(RuntimeOrigin::signed(BOB), game_id),
+			Error::<TestRuntime>::NotAuthorized
+		);
+
+		assert_ok!(PalletGames::burn_game(RuntimeOrigin::signed(ALICE), game_id));
+
+		System::assert_last_event(
+			Event::<TestRuntime>::GameBurned { owner: ALICE, id: game_id }.into(),
+		);
+		assert!(!Games::<TestRuntime>::contains_key(game_id));
+		assert_eq!(owned_by(ALICE), vec![kept]);
+		assert_eq!(GameCount::<TestRuntime>::get(), 2);
+		assert_eq!(LiveGameCount::<TestRuntime>::get(), 1);
+		assert_noop!(
+			PalletGames::burn_game(RuntimeOrigin::signed(ALICE), game_id),
+			Error::<TestRuntime>::GameNotFound
+		);
+	});
+}
+
+#[test]
+fn operators_can_burn_but_not_during_an_auction() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		let game_id = create_game_for(ALICE);
+		assert_ok!(PalletGames::set_operator(RuntimeOrigin::signed(ALICE), BOB, true));
+		assert_ok!(PalletGames::create_auction(RuntimeOrigin::signed(ALICE), game_id, 10, 3));
+		assert_noop!(
+			PalletGames::burn_game(RuntimeOrigin::signed(BOB), game_id),
+			Error::<TestRuntime>::GameLocked
+		);
+
+		assert_ok!(PalletGames::cancel_auction(RuntimeOrigin::signed(ALICE), game_id));
+		assert_ok!(PalletGames::burn_game(RuntimeOrigin::signed(BOB), game_id));
+
+		assert!(!Games::<TestRuntime>::contains_key(game_id));
+		assert_eq!(LiveGameCount::<TestRuntime>::get(), 0);
+	});
+}
+
+#[test]
+fn burning_refunds_offers_and_locked_metadata() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&ALICE, 100));
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		let game_id = create_game_for(ALICE);
+		assert_ok!(PalletGames::set_metadata(
+			RuntimeOrigin::signed(ALICE),
+			game_id,
+			bounded("Chess"),
+			bounded("ipfs://x"),
+			Default::default()
+		));
+		assert_ok!(PalletGames::lock_metadata(RuntimeOrigin::signed(ALICE), game_id));
+		assert_ok!(PalletGames::make_offer(RuntimeOrigin::signed(BOB), game_id, 30, 10));
+
+		assert_ok!(PalletGames::burn_game(RuntimeOrigin::signed(ALICE), game_id));
+
+		assert!(!GameMetadata::<TestRuntime>::contains_key(game_id));
+		assert!(Offers::<TestRuntime>::get(game_id).is_empty());
+		assert_eq!(PalletBalances::total_balance_on_hold(&ALICE), 0);
+		assert_eq!(PalletBalances::total_balance_on_hold(&BOB), 0);
+		assert_eq!(PalletBalances::free_balance(BOB), 100);
+	});
+}
+
+#[test]
+fn query_helpers_report_games_listings_and_supply() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		let cheap = create_game_for(ALICE);
+		let dear = create_game_for(ALICE);
+		let unlisted = create_game_for(ALICE);
+		let burned = create_game_for(BOB);
+		assert_ok!(PalletGames::set_game_price(RuntimeOrigin::signed(ALICE), dear, Some(50), None));
+		assert_ok!(PalletGames::set_game_price(
+			RuntimeOrigin::signed(ALICE),
+			cheap,
+			Some(20),
+			None
+		));
+		assert_ok!(PalletGames::burn_game(RuntimeOrigin::signed(BOB), burned));
+
+		assert_eq!(
+			PalletGames::game_info(dear),
+			Some(GameInfo {
+				id: dear,
+				owner: ALICE,
+				creator: ALICE,
+				royalty: Permill::zero(),
+				price: Some(50)
+			})
+		);
+		assert_eq!(PalletGames::game_info(burned), None);
+
+		let listed: Vec<_> = PalletGames::listings(0, 10).into_iter().map(|g| g.id).collect();
+		assert_eq!(listed, vec![cheap, dear]);
+		assert_eq!(PalletGames::listings(1, 10)[0].id, dear);
+
+		let mut owned: Vec<_> =
+			PalletGames::games_of(ALICE, 0, 10).into_iter().map(|g| g.id).collect();
+		owned.sort();
+		let mut expected = vec![cheap, dear, unlisted];
+		expected.sort();
+		assert_eq!(owned, expected);
+		assert_eq!(PalletGames::games_of(ALICE, 1, 1).len(), 1);
+		assert!(PalletGames::games_of(ALICE, 3, 10).is_empty());
+
+		assert_eq!(PalletGames::supply(), Supply { total: 4, live: 3 });
+
+		// Only `MaxListingsScanned` games are read per query.
+		for id in [unlisted, create_game_for(BOB), create_game_for(BOB)] {
+			let owner = Games::<TestRuntime>::get(id).unwrap().owner;
+			assert_ok!(PalletGames::set_game_price(
+				RuntimeOrigin::signed(owner),
+				id,
+				Some(30),
+				None
+			));
+		}
+		assert_eq!(PalletGames::listings(0, 10).len(), 4);
+
+		// Paging reads as many games at a time, but says where to go on.
+		let first = PalletGames::listings_from(None, 10);
+		assert_eq!(first.games.len(), 4);
+		let rest = PalletGames::listings_from(first.next, 10);
+		assert_eq!(rest.games.len(), 1);
+		assert_eq!(rest.next, None);
+		let mut listed: Vec<_> = first.games.iter().chain(&rest.games).map(|g| g.id).collect();
+		listed.sort();
+		listed.dedup();
+		assert_eq!(listed.len(), 5);
+
+		let single = PalletGames::listings_from(None, 1);
+		assert_eq!(single.games.len(), 1);
+		assert_eq!(PalletGames::listings_from(single.next, 10).games.len(), 4);
+	});
+}
+
+#[test]
+fn expired_listings_cannot_be_bought_and_are_swept() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		let game_id = create_game_for(ALICE);
+		assert_ok!(PalletGames::set_game_price(
+			RuntimeOrigin::signed(ALICE),
+			game_id,
+			Some(40),
+			Some(5)
+		));
+		System::assert_last_event(
+			Event::<TestRuntime>::PriceUpdated {
+				owner: ALICE,
+				id: game_id,
+				price: Some(40),
+				expiry: Some(5),
+			}
+			.into(),
+		);
+
+		System::set_block_number(5);
+		assert_eq!(PalletGames::current_price(game_id), None);
+		assert_noop!(
+			PalletGames::buy_game(RuntimeOrigin::signed(BOB), game_id, 40),
+			Error::<TestRuntime>::NotForSale
+		);
+
+		PalletGames::on_idle(5, Weight::MAX);
+
+		System::assert_last_event(Event::<TestRuntime>::ListingExpired { id: game_id }.into());
+		assert_eq!(Games::<TestRuntime>::get(game_id).unwrap().value, None);
+		assert!(!ListingExpiry::<TestRuntime>::contains_key(game_id));
+		assert!(!ListingsExpiringAt::<TestRuntime>::contains_key(5));
+	});
+}
+
+#[test]
+fn transfers_take_the_game_off_sale() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&CHARLIE, 100));
+		let game_id = create_game_for(ALICE);
+		assert_ok!(PalletGames::set_game_price(
+			RuntimeOrigin::signed(ALICE),
+			game_id,
+			Some(40),
+			Some(6)
+		));
+
+		assert_ok!(PalletGames::transfer_game(RuntimeOrigin::signed(ALICE), BOB, game_id));
+
+		assert_eq!(Games::<TestRuntime>::get(game_id).unwrap().value, None);
+		assert!(!ListingExpiry::<TestRuntime>::contains_key(game_id));
+		assert!(ListingsExpiringAt::<TestRuntime>::get(6).is_empty());
+		assert_noop!(
+			PalletGames::buy_game(RuntimeOrigin::signed(CHARLIE), game_id, 40),
+			Error::<TestRuntime>::NotForSale
+		);
+
+		// The same holds for moves made by an approved delegate.
+		assert_ok!(PalletGames::set_game_price(
+			RuntimeOrigin::signed(BOB),
+			game_id,
+			Some(40),
+			None
+		));
+		assert_ok!(PalletGames::approve(RuntimeOrigin::signed(BOB), game_id, Some(DAVE)));
+		assert_ok!(PalletGames::transfer_from(RuntimeOrigin::signed(DAVE), BOB, ALICE, game_id));
+		assert_noop!(
+			PalletGames::buy_game(RuntimeOrigin::signed(CHARLIE), game_id, 40),
+			Error::<TestRuntime>::NotForSale
+		);
+	});
+}
+
+#[test]
+fn listing_expiry_is_validated_and_reindexed() {
+	build_and_execute(|| {
+		System::set_block_number(3);
+		let game_id = create_game_for(ALICE);
+		assert_noop!(
+			PalletGames::set_game_price(RuntimeOrigin::signed(ALICE), game_id, Some(40), Some(3)),
+			Error::<TestRuntime>::ListingExpiryInPast
+		);
+		for _ in 0..2 {
+			let other = create_game_for(BOB);
+			assert_ok!(PalletGames::set_game_price(
+				RuntimeOrigin::signed(BOB),
+				other,
+				Some(10),
+				Some(8)
+			));
+		}
+		assert_noop!(
+			PalletGames::set_game_price(RuntimeOrigin::signed(ALICE), game_id, Some(40), Some(8)),
+			Error::<TestRuntime>::TooManyListingsExpiring
+		);
+
+		assert_ok!(PalletGames::set_game_price(
+			RuntimeOrigin::signed(ALICE),
+			game_id,
+			Some(40),
+			Some(6)
+		));
+		assert_ok!(PalletGames::set_game_price(
+			RuntimeOrigin::signed(ALICE),
+			game_id,
+			Some(30),
+			Some(7)
+		));
+		assert!(ListingsExpiringAt::<TestRuntime>::get(6).is_empty());
+		assert_eq!(ListingsExpiringAt::<TestRuntime>::get(7).into_inner(), vec![game_id]);
+
+		// Delisting drops the expiry along with the price.
+		assert_ok!(PalletGames::set_game_price(
+			RuntimeOrigin::signed(ALICE),
+			game_id,
+			None,
+			Some(9)
+		));
+		assert!(!ListingExpiry::<TestRuntime>::contains_key(game_id));
+		assert!(ListingsExpiringAt::<TestRuntime>::get(7).is_empty());
+	});
+}
+
+#[test]
+fn listing_sweep_stays_within_its_weight_budget() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		let first = create_game_for(ALICE);
+		let second = create_game_for(ALICE);
+		assert_ok!(PalletGames::set_game_price(
+			RuntimeOrigin::signed(ALICE),
+			first,
+			Some(10),
+			Some(3)
+		));
+		assert_ok!(PalletGames::set_game_price(
+			RuntimeOrigin::signed(ALICE),
+			second,
+			Some(10),
+			Some(4)
+		));
+		let db: frame_support::weights::RuntimeDbWeight =
+			<TestRuntime as frame_system::Config>::DbWeight::get();
+		let expire = <TestRuntime as Config>::WeightInfo::expire_listings;
+		// Enough for the empty blocks 1 and 2 and a worst-case block 3, but not for block 4.
+		let budget = db
+			.reads_writes(1, 1)
+			.saturating_add(expire(0).saturating_mul(2))
+			.saturating_add(expire(2));
+
+		assert_eq!(PalletGames::on_idle(10, Weight::zero()), Weight::zero());
+		PalletGames::on_idle(10, budget);
+		assert_eq!(Games::<TestRuntime>::get(first).unwrap().value, None);
+		assert_eq!(Games::<TestRuntime>::get(second).unwrap().value, Some(10));
+		assert_eq!(ListingSweepCursor::<TestRuntime>::get(), Some(4));
+
+		PalletGames::on_idle(10, Weight::MAX);
+		assert_eq!(Games::<TestRuntime>::get(second).unwrap().value, None);
+		assert_eq!(ListingSweepCursor::<TestRuntime>::get(), Some(11));
+	});
+}
+
+#[test]
+fn rented_games_stay_with_their_owner_until_the_rental_ends() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		let game_id = create_game_for(ALICE);
+		assert_ok!(PalletGames::set_game_price(
+			RuntimeOrigin::signed(ALICE),
+			game_id,
+			Some(10),
+			Some(3)
+		));
+		assert_noop!(
+			PalletGames::lend_game(RuntimeOrigin::signed(ALICE), game_id, BOB, 1, None),
+			Error::<TestRuntime>::RentalEndInPast
+		);
+		assert_noop!(
+			PalletGames::lend_game(RuntimeOrigin::signed(BOB), game_id, CHARLIE, 5, None),
+			Error::<TestRuntime>::NotAuthorized
+		);
+
+		assert_ok!(PalletGames::lend_game(RuntimeOrigin::signed(ALICE), game_id, BOB, 5, None));
+		System::assert_last_event(
+			Event::<TestRuntime>::RentalStarted { id: game_id, renter: BOB, until: 5 }.into(),
+		);
+		assert_eq!(PalletGames::current_user(game_id), Some(BOB));
+		assert_eq!(PalletGames::current_price(game_id), None);
+		assert!(!ListingExpiry::<TestRuntime>::contains_key(game_id));
+		assert_noop!(
+			PalletGames::transfer_game(RuntimeOrigin::signed(ALICE), CHARLIE, game_id),
+			Error::<TestRuntime>::GameRented
+		);
+		assert_noop!(
+			PalletGames::set_game_price(RuntimeOrigin::signed(ALICE), game_id, Some(10), None),
+			Error::<TestRuntime>::GameRented
+		);
+		assert_noop!(
+			PalletGames::create_auction(RuntimeOrigin::signed(ALICE), game_id, 10, 8),
+			Error::<TestRuntime>::GameRented
+		);
+		assert_noop!(
+			PalletGames::burn_game(RuntimeOrigin::signed(ALICE), game_id),
+			Error::<TestRuntime>::GameRented
+		);
+
+		// The rental lapses on its own.
+		System::set_block_number(5);
+		assert_eq!(PalletGames::current_user(game_id), Some(ALICE));
+		assert_ok!(PalletGames::transfer_game(RuntimeOrigin::signed(ALICE), CHARLIE, game_id));
+		let game = Games::<TestRuntime>::get(game_id).unwrap();
+		assert_eq!(game.owner, CHARLIE);
+		assert!(game.rental.is_none());
+	});
+}
+
+#[test]
+fn paid_rentals_start_once_the_renter_pays() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		let game_id = create_game_for(ALICE);
+		assert_noop!(
+			PalletGames::accept_rental(RuntimeOrigin::signed(BOB), game_id),
+			Error::<TestRuntime>::RentalNotFound
+		);
+
+		assert_ok!(PalletGames::lend_game(
+			RuntimeOrigin::signed(ALICE),
+			game_id,
+			BOB,
+			10,
+			Some(30)
+		));
+		System::assert_last_event(
+			Event::<TestRuntime>::RentalOffered { id: game_id, renter: BOB, until: 10, fee: 30 }
+				.into(),
+		);
+		assert_eq!(PalletGames::current_user(game_id), Some(ALICE));
+		assert_noop!(
+			PalletGames::accept_rental(RuntimeOrigin::signed(CHARLIE), game_id),
+			Error::<TestRuntime>::NotAuthorized
+		);
+
+		assert_ok!(PalletGames::accept_rental(RuntimeOrigin::signed(BOB), game_id));
+		assert_eq!(PalletBalances::free_balance(BOB), 70);
+		assert_eq!(PalletBalances::free_balance(ALICE), 30);
+		assert_eq!(PalletGames::current_user(game_id), Some(BOB));
+		assert!(!RentalOffers::<TestRuntime>::contains_key(game_id));
+	});
+}
+
+#[test]
+fn rental_offers_lapse_with_the_offer_window_or_a_transfer() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		let game_id = create_game_for(ALICE);
+		assert_ok!(PalletGames::lend_game(RuntimeOrigin::signed(ALICE), game_id, BOB, 4, Some(30)));
+
+		System::set_block_number(4);
+		assert_noop!(
+			PalletGames::accept_rental(RuntimeOrigin::signed(BOB), game_id),
+			Error::<TestRuntime>::RentalEndInPast
+		);
+
+		assert_ok!(PalletGames::lend_game(RuntimeOrigin::signed(ALICE), game_id, BOB, 9, Some(30)));
+		assert_ok!(PalletGames::transfer_game(RuntimeOrigin::signed(ALICE), CHARLIE, game_id));
+		assert_noop!(
+			PalletGames::accept_rental(RuntimeOrigin::signed(BOB), game_id),
+			Error::<TestRuntime>::RentalNotFound
+		);
+		assert_eq!(PalletBalances::free_balance(BOB), 100);
+	});
+}
+
+#[test]
+fn create_games_mints_a_batch_with_one_summary_event() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			PalletGames::create_games(RuntimeOrigin::signed(ALICE), 0, Permill::zero()),
+			Error::<TestRuntime>::EmptyBatch
+		);
+		assert_noop!(
+			PalletGames::create_games(RuntimeOrigin::signed(ALICE), 4, Permill::zero()),
+			Error::<TestRuntime>::BatchTooLarge
+		);
+		assert_noop!(
+			PalletGames::create_games(RuntimeOrigin::signed(ALICE), 2, Permill::from_percent(50)),
+			Error::<TestRuntime>::RoyaltyTooHigh
+		);
+
+		assert_ok!(PalletGames::create_games(RuntimeOrigin::signed(ALICE), 3, Permill::zero()));
+
+		System::assert_last_event(
+			Event::<TestRuntime>::GamesCreated { owner: ALICE, count: 3 }.into(),
+		);
+		let created = System::events()
+			.iter()
+			.filter(|record| {
+				matches!(
+					record.event,
+					RuntimeEvent::PalletGames(Event::GameCreated { owner: ALICE, .. })
+				)
+			})
+			.count();
+		assert_eq!(created, 3);
+		assert_eq!(owned_by(ALICE).len(), 3);
+		assert_eq!(PalletGames::supply(), Supply { total: 3, live: 3 });
+	});
+}
+
+#[test]
+fn transfer_games_moves_every_game_or_none() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		let first = create_game_for(ALICE);
+		let second = create_game_for(ALICE);
+		let not_hers = create_game_for(BOB);
+		let batch =
+			|transfers: Vec<(u64, [u8; 32])>| -> BoundedVec<_, _> { transfers.try_into().unwrap() };
+
+		assert_noop!(
+			PalletGames::transfer_games(RuntimeOrigin::signed(ALICE), batch(vec![])),
+			Error::<TestRuntime>::EmptyBatch
+		);
+		assert_noop!(
+			PalletGames::transfer_games(
+				RuntimeOrigin::signed(ALICE),
+				batch(vec![(BOB, first), (CHARLIE, not_hers)])
+			),
+			Error::<TestRuntime>::NotAuthorized
+		);
+		assert_eq!(Games::<TestRuntime>::get(first).unwrap().owner, ALICE);
+
+		assert_ok!(PalletGames::transfer_games(
+			RuntimeOrigin::signed(ALICE),
+			batch(vec![(BOB, first), (CHARLIE, second)])
+		));
+
+		System::assert_has_event(
+			Event::<TestRuntime>::GameTransferred { from: ALICE, to: CHARLIE, id: second }.into(),
+		);
+		System::assert_last_event(
+			Event::<TestRuntime>::GamesTransferred { from: ALICE, count: 2 }.into(),
+		);
+		assert_eq!(owned_by(ALICE), Vec::<[u8; 32]>::new());
+		assert_eq!(Games::<TestRuntime>::get(first).unwrap().owner, BOB);
+		assert_eq!(Games::<TestRuntime>::get(second).unwrap().owner, CHARLIE);
+	});
+}
+
+#[test]
+fn game_ids_come_from_the_configured_randomness() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		let predicted = PalletGames::gen_game_key();
+
+		RandomSeed::set(H256::repeat_byte(1));
+		let game_id = create_game_for(ALICE);
+
+		assert_ne!(game_id, predicted);
+		assert!(Games::<TestRuntime>::contains_key(game_id));
+		// The next ID differs even though the randomness has not changed.
+		assert_ne!(PalletGames::gen_game_key(), game_id);
+	});
+}
+
+fn genesis_ext(games: pallet_games::GenesisConfig<TestRuntime>) -> sp_io::TestExternalities {
+	RuntimeGenesisConfig {
+		pallet_balances: pallet_balances::GenesisConfig {
+			balances: vec![(ALICE, 1_000), (BOB, 1_000)],
+		},
+		pallet_games: games,
+		..Default::default()
+	}
+	.build_storage()
+	.unwrap()
+	.into()
+}
+
+#[test]
+fn genesis_games_are_minted_listed_and_described() {
+	let metadata =
+		(b"chess".to_vec(), b"ipfs://c".to_vec(), vec![(b"mode".to_vec(), b"pvp".to_vec())]);
+	let games = pallet_games::GenesisConfig {
+		games: vec![(ALICE, [1u8; 32], Some(50), Some(metadata)), (BOB, [2u8; 32], None, None)],
+	};
+
+	genesis_ext(games).execute_with(|| {
+		let chess = Games::<TestRuntime>::get([1u8; 32]).unwrap();
+		assert_eq!((chess.owner, chess.creator), (ALICE, ALICE));
+		assert_eq!(PalletGames::current_price([1u8; 32]), Some(50));
+		let metadata = GameMetadata::<TestRuntime>::get([1u8; 32]).unwrap();
+		assert_eq!(metadata.name, bounded("chess"));
+		assert_eq!(PalletBalances::total_balance_on_hold(&ALICE), metadata.deposit);
+
+		assert_eq!(owned_by(BOB), vec![[2u8; 32]]);
+		assert_eq!(PalletGames::current_price([2u8; 32]), None);
+		assert_eq!(PalletGames::supply(), Supply { total: 2, live: 2 });
+		PalletGames::do_try_state().unwrap();
+	});
+}
+
+#[test]
+#[should_panic(expected = "genesis games must have unique IDs")]
+fn genesis_rejects_duplicate_game_ids() {
+	let games = pallet_games::GenesisConfig {
+		games: vec![(ALICE, [1u8; 32], None, None), (BOB, [1u8; 32], None, None)],
+	};
+	genesis_ext(games);
+}
+
+#[test]
+#[should_panic(expected = "genesis metadata strings must fit within StringLimit")]
+fn genesis_rejects_oversized_metadata() {
+	let metadata = (b"much too long".to_vec(), Vec::new(), Vec::new());
+	let games =
+		pallet_games::GenesisConfig { games: vec![(ALICE, [1u8; 32], None, Some(metadata))] };
+	genesis_ext(games);
+}
+
+#[test]
+fn try_state_catches_an_inconsistent_ownership_index() {
+	build_and_execute(|| {
+		let game_id = create_game_for(ALICE);
+		assert_ok!(PalletGames::do_try_state());
+
+		GamesOwnedBy::<TestRuntime>::insert(BOB, game_id, ());
+		assert!(PalletGames::do_try_state().is_err());
+		GamesOwnedBy::<TestRuntime>::remove(BOB, game_id);
+
+		GamesOwnedBy::<TestRuntime>::remove(ALICE, game_id);
+		assert!(PalletGames::do_try_state().is_err());
+		GamesOwnedBy::<TestRuntime>::insert(ALICE, game_id, ());
+
+		LiveGameCount::<TestRuntime>::put(2);
+		assert!(PalletGames::do_try_state().is_err());
+		LiveGameCount::<TestRuntime>::put(1);
+	});
+}
+
+fn commit(bidder: u64, game_id: [u8; 32], amount: u64, salt: u8, deposit: u64) -> DispatchResult {
+	let commitment = PalletGames::sealed_bid_commitment(&bidder, amount, [salt; 32]);
+	PalletGames::commit_bid(RuntimeOrigin::signed(bidder), game_id, commitment, deposit)
+}
+
+#[test]
+fn sealed_auction_goes_to_the_highest_revealed_bid() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		for bidder in [BOB, CHARLIE, DAVE] {
+			assert_ok!(PalletBalances::mint_into(&bidder, 100));
+		}
+		let game_id = create_game_for(ALICE);
+		assert_ok!(PalletGames::create_sealed_auction(
+			RuntimeOrigin::signed(ALICE),
+			game_id,
+			10,
+			3,
+			5
+		));
+		assert_ok!(commit(BOB, game_id, 30, 1, 50));
+		assert_ok!(commit(CHARLIE, game_id, 40, 2, 40));
+		assert_ok!(commit(DAVE, game_id, 20, 3, 60));
+		System::assert_last_event(
+			Event::<TestRuntime>::BidCommitted { bidder: DAVE, id: game_id, deposit: 60 }.into(),
+		);
+		assert_noop!(
+			PalletGames::reveal_bid(RuntimeOrigin::signed(BOB), game_id, 30, [1; 32]),
+			Error::<TestRuntime>::NotRevealing
+		);
+		assert_noop!(
+			PalletGames::transfer_game(RuntimeOrigin::signed(ALICE), BOB, game_id),
+			Error::<TestRuntime>::GameLocked
+		);
+
+		run_to_block(3);
+		assert_noop!(commit(BOB, game_id, 45, 4, 50), Error::<TestRuntime>::AuctionClosed);
+		assert_ok!(PalletGames::reveal_bid(RuntimeOrigin::signed(BOB), game_id, 30, [1; 32]));
+		assert_noop!(
+			PalletGames::reveal_bid(RuntimeOrigin::signed(CHARLIE), game_id, 40, [9; 32]),
+			Error::<TestRuntime>::CommitmentMismatch
+		);
+		assert_ok!(PalletGames::reveal_bid(RuntimeOrigin::signed(CHARLIE), game_id, 40, [2; 32]));
+		System::assert_last_event(
+			Event::<TestRuntime>::BidRevealed { bidder: CHARLIE, id: game_id, amount: 40 }.into(),
+		);
+		// Outbid, so Bob's deposit is already back.
+		assert_eq!(PalletBalances::total_balance_on_hold(&BOB), 0);
+
+		run_to_block(5);
+
+		System::assert_has_event(
+			Event::<TestRuntime>::UnrevealedBidSettled {
+				bidder: DAVE,
+				id: game_id,
+				deposit: 60,
+				slashed: false,
+			}
+			.into(),
+		);
+		System::assert_has_event(
+			Event::<TestRuntime>::AuctionSettled { winner: CHARLIE, id: game_id, price: 40 }.into(),
+		);
+		assert_eq!(Games::<TestRuntime>::get(game_id).unwrap().owner, CHARLIE);
+		assert_eq!(PalletBalances::free_balance(ALICE), 40);
+		assert_eq!(PalletBalances::free_balance(CHARLIE), 60);
+		assert_eq!(PalletBalances::free_balance(BOB), 100);
+		assert_eq!(PalletBalances::free_balance(DAVE), 100);
+		assert!(!SealedAuctions::<TestRuntime>::contains_key(game_id));
+		assert!(!SealedBids::<TestRuntime>::contains_key(game_id));
+	});
+}
+
+#[test]
+fn sealed_auctions_that_do_not_fit_the_settlement_budget_settle_later() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		let ids = [create_game_for(ALICE), create_game_for(ALICE)];
+		for (salt, id) in ids.into_iter().enumerate() {
+			assert_ok!(PalletGames::create_sealed_auction(
+				RuntimeOrigin::signed(ALICE),
+				id,
+				10,
+				3,
+				5
+			));
+			assert_ok!(commit(BOB, id, 20, salt as u8, 20));
+		}
+		run_to_block(3);
+		for (salt, id) in ids.into_iter().enumerate() {
+			assert_ok!(PalletGames::reveal_bid(
+				RuntimeOrigin::signed(BOB),
+				id,
+				20,
+				[salt as u8; 32]
+			));
+		}
+		// Leave room for a single settlement per block.
+		let db: frame_support::weights::RuntimeDbWeight =
+			<TestRuntime as frame_system::Config>::DbWeight::get();
+		MaxSettlementWeight::set(
+			db.reads_writes(1, 1)
+				.saturating_add(<() as WeightInfo>::settle_sealed_auction()),
+		);
+
+		run_to_block(5);
+		assert_eq!(Games::<TestRuntime>::get(ids[0]).unwrap().owner, BOB);
+		assert_eq!(Games::<TestRuntime>::get(ids[1]).unwrap().owner, ALICE);
+		assert!(SealedAuctions::<TestRuntime>::contains_key(ids[1]));
+
+		run_to_block(6);
+		assert_eq!(Games::<TestRuntime>::get(ids[1]).unwrap().owner, BOB);
+		assert!(!SealedAuctionsEndingAt::<TestRuntime>::contains_key(5));
+		assert_eq!(PalletBalances::balance(&ALICE), 40);
+		assert_eq!(PalletBalances::total_balance_on_hold(&BOB), 0);
+	});
+}
+
+#[test]
+fn unrevealed_sealed_bids_follow_the_configured_policy() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		SealedBidPolicy::set(UnrevealedBidPolicy::Slash);
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		assert_ok!(PalletBalances::mint_into(&CHARLIE, 100));
+		let game_id = create_game_for(ALICE);
+		assert_ok!(PalletGames::create_sealed_auction(
+			RuntimeOrigin::signed(ALICE),
+			game_id,
+			10,
+			3,
+			5
+		));
+		assert_ok!(commit(BOB, game_id, 30, 1, 50));
+		assert_ok!(commit(CHARLIE, game_id, 5, 2, 20));
+		let issuance = PalletBalances::total_issuance();
+
+		run_to_block(3);
+		// A bid below the reserve cannot be revealed, so it counts as unrevealed.
+		assert_noop!(
+			PalletGames::reveal_bid(RuntimeOrigin::signed(CHARLIE), game_id, 5, [2; 32]),
+			Error::<TestRuntime>::BidTooLow
+		);
+		run_to_block(5);
+
+		System::assert_last_event(Event::<TestRuntime>::AuctionEnded { id: game_id }.into());
+		assert_eq!(PalletBalances::free_balance(BOB), 50);
+		assert_eq!(PalletBalances::free_balance(CHARLIE), 80);
+		assert_eq!(PalletBalances::total_issuance(), issuance - 70);
+		assert_eq!(Games::<TestRuntime>::get(game_id).unwrap().owner, ALICE);
+		assert_ok!(PalletGames::transfer_game(RuntimeOrigin::signed(ALICE), BOB, game_id));
+	});
+}
+
+#[test]
+fn sealed_bids_are_validated() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		for bidder in [BOB, CHARLIE, DAVE, 5] {
+			assert_ok!(PalletBalances::mint_into(&bidder, 100));
+		}
+		let game_id = create_game_for(ALICE);
+		assert_noop!(
+			PalletGames::create_sealed_auction(RuntimeOrigin::signed(ALICE), game_id, 10, 3, 3),
+			Error::<TestRuntime>::InvalidAuctionPhases
+		);
+		assert_noop!(
+			PalletGames::create_sealed_auction(RuntimeOrigin::signed(ALICE), game_id, 10, 1, 3),
+			Error::<TestRuntime>::AuctionEndInPast
+		);
+		assert_ok!(PalletGames::create_sealed_auction(
+			RuntimeOrigin::signed(ALICE),
+			game_id,
+			10,
+			3,
+			5
+		));
+		assert_noop!(
+			PalletGames::create_auction(RuntimeOrigin::signed(ALICE), game_id, 10, 4),
+			Error::<TestRuntime>::GameLocked
+		);
+
+		assert_noop!(commit(ALICE, game_id, 20, 1, 20), Error::<TestRuntime>::TransferToSelf);
+		assert_noop!(commit(BOB, game_id, 5, 1, 5), Error::<TestRuntime>::BidTooLow);
+		assert_ok!(commit(BOB, game_id, 20, 1, 20));
+		assert_noop!(commit(BOB, game_id, 30, 1, 30), Error::<TestRuntime>::BidAlreadyCommitted);
+		assert_ok!(commit(CHARLIE, game_id, 50, 2, 40));
+		assert_ok!(commit(DAVE, game_id, 20, 3, 20));
+		assert_noop!(commit(5, game_id, 20, 4, 20), Error::<TestRuntime>::TooManySealedBids);
+		assert_noop!(
+			PalletGames::cancel_auction(RuntimeOrigin::signed(ALICE), game_id),
+			Error::<TestRuntime>::AuctionHasBids
+		);
+
+		run_to_block(3);
+		assert_noop!(
+			PalletGames::reveal_bid(RuntimeOrigin::signed(5), game_id, 20, [4; 32]),
+			Error::<TestRuntime>::BidNotFound
+		);
+		assert_noop!(
+			PalletGames::reveal_bid(RuntimeOrigin::signed(CHARLIE), game_id, 50, [2; 32]),
+			Error::<TestRuntime>::BidExceedsDeposit
+		);
+		assert_ok!(PalletGames::reveal_bid(RuntimeOrigin::signed(BOB), game_id, 20, [1; 32]));
+		assert_noop!(
+			PalletGames::reveal_bid(RuntimeOrigin::signed(BOB), game_id, 20, [1; 32]),
+			Error::<TestRuntime>::BidAlreadyRevealed
+		);
+		// A tie does not beat the bid revealed first.
+		assert_ok!(PalletGames::reveal_bid(RuntimeOrigin::signed(DAVE), game_id, 20, [3; 32]));
+		assert_eq!(PalletBalances::total_balance_on_hold(&DAVE), 0);
+		assert_eq!(
+			SealedAuctions::<TestRuntime>::get(game_id).unwrap().highest_bid,
+			Some((BOB, 20))
+		);
+
+		run_to_block(5);
+		assert_noop!(
+			PalletGames::reveal_bid(RuntimeOrigin::signed(CHARLIE), game_id, 40, [2; 32]),
+			Error::<TestRuntime>::AuctionNotFound
+		);
+		assert_eq!(Games::<TestRuntime>::get(game_id).unwrap().owner, BOB);
+	});
+}
+
+#[test]
+fn sealed_auctions_without_bids_can_be_cancelled() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		let game_id = create_game_for(ALICE);
+		assert_ok!(PalletGames::create_sealed_auction(
+			RuntimeOrigin::signed(ALICE),
+			game_id,
+			10,
+			3,
+			5
+		));
+		assert_noop!(
+			PalletGames::cancel_auction(RuntimeOrigin::signed(BOB), game_id),
+			Error::<TestRuntime>::NotAuthorized
+		);
+
+		assert_ok!(PalletGames::cancel_auction(RuntimeOrigin::signed(ALICE), game_id));
+
+		assert!(!SealedAuctions::<TestRuntime>::contains_key(game_id));
+		assert!(SealedAuctionsEndingAt::<TestRuntime>::get(5).is_empty());
+		assert_ok!(PalletGames::transfer_game(RuntimeOrigin::signed(ALICE), BOB, game_id));
+	});
+}
+
+fn swap_games(ids: &[[u8; 32]]) -> SwapGames<TestRuntime> {
+	ids.to_vec().try_into().unwrap()
+}
+
+fn swap_deposit_of(who: u64) -> u64 {
+	PalletBalances::balance_on_hold(&HoldReason::SwapDeposit.into(), &who)
+}
+
+#[test]
+fn accepted_swaps_move_every_game_and_the_top_up() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&ALICE, 100));
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		let alices = create_game_for(ALICE);
+		let bobs = [create_game_for(BOB), create_game_for(BOB)];
+		assert_ok!(PalletGames::set_game_price(RuntimeOrigin::signed(BOB), bobs[0], Some(5), None));
+
+		assert_ok!(PalletGames::propose_swap(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			swap_games(&[alices]),
+			swap_games(&bobs),
+			Some(TopUp::Give(10)),
+			10
+		));
+		System::assert_last_event(
+			Event::<TestRuntime>::SwapProposed { swap_id: 0, proposer: ALICE, counterparty: BOB }
+				.into(),
+		);
+		assert_eq!(swap_deposit_of(ALICE), 5);
+		assert_noop!(
+			PalletGames::accept_swap(RuntimeOrigin::signed(CHARLIE), 0),
+			Error::<TestRuntime>::NotAuthorized
+		);
+
+		assert_ok!(PalletGames::accept_swap(RuntimeOrigin::signed(BOB), 0));
+
+		System::assert_last_event(Event::<TestRuntime>::SwapAccepted { swap_id: 0 }.into());
+		assert_eq!(owned_by(ALICE).len(), 2);
+		assert!(bobs.iter().all(|id| Games::<TestRuntime>::get(id).unwrap().owner == ALICE));
+		assert_eq!(owned_by(BOB), vec![alices]);
+		assert_eq!(Games::<TestRuntime>::get(bobs[0]).unwrap().value, None);
+		assert_eq!(PalletBalances::free_balance(ALICE), 90);
+		assert_eq!(PalletBalances::free_balance(BOB), 110);
+		assert_eq!(swap_deposit_of(ALICE), 0);
+		assert!(!Swaps::<TestRuntime>::contains_key(0));
+		assert!(!SwapsByGame::<TestRuntime>::contains_key(alices));
+		assert!(SwapsExpiringAt::<TestRuntime>::get(10).is_empty());
+	});
+}
+
+#[test]
+fn failed_swaps_move_nothing() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&ALICE, 100));
+		let alices = create_game_for(ALICE);
+		let bobs = create_game_for(BOB);
+		assert_ok!(PalletGames::propose_swap(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			swap_games(&[alices]),
+			swap_games(&[bobs]),
+			Some(TopUp::Want(50)),
+			10
+		));
+
+		// Bob cannot pay the top-up, so neither game moves.
+		assert_noop!(
+			PalletGames::accept_swap(RuntimeOrigin::signed(BOB), 0),
+			TokenError::FundsUnavailable
+		);
+
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		assert_ok!(PalletGames::create_auction(RuntimeOrigin::signed(ALICE), alices, 10, 5));
+		assert_noop!(
+			PalletGames::accept_swap(RuntimeOrigin::signed(BOB), 0),
+			Error::<TestRuntime>::GameLocked
+		);
+		assert_eq!(owned_by(ALICE), vec![alices]);
+		assert_eq!(owned_by(BOB), vec![bobs]);
+	});
+}
+
+#[test]
+fn swaps_are_cancelled_when_a_game_changes_owner_or_they_expire() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&ALICE, 100));
+		assert_ok!(PalletBalances::mint_into(&CHARLIE, 100));
+		let alices = create_game_for(ALICE);
+		let bobs = create_game_for(BOB);
+		let charlies = create_game_for(CHARLIE);
+		assert_ok!(PalletGames::propose_swap(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			swap_games(&[alices]),
+			swap_games(&[bobs]),
+			None,
+			10
+		));
+		assert_ok!(PalletGames::propose_swap(
+			RuntimeOrigin::signed(CHARLIE),
+			ALICE,
+			swap_games(&[charlies]),
+			swap_games(&[]),
+			None,
+			5
+		));
+
+		// Moving a wanted game leaves the proposal open, but it can no longer be accepted.
+		assert_ok!(PalletGames::transfer_game(RuntimeOrigin::signed(BOB), DAVE, bobs));
+		assert!(Swaps::<TestRuntime>::contains_key(0));
+		assert_noop!(
+			PalletGames::accept_swap(RuntimeOrigin::signed(BOB), 0),
+			Error::<TestRuntime>::NotAuthorized
+		);
+
+		assert_ok!(PalletGames::transfer_game(RuntimeOrigin::signed(ALICE), DAVE, alices));
+
+		System::assert_has_event(Event::<TestRuntime>::SwapCancelled { swap_id: 0 }.into());
+		assert!(!SwapsByGame::<TestRuntime>::contains_key(alices));
+		assert_eq!(swap_deposit_of(ALICE), 0);
+		assert_eq!(swap_deposit_of(CHARLIE), 5);
+		assert_noop!(
+			PalletGames::accept_swap(RuntimeOrigin::signed(BOB), 0),
+			Error::<TestRuntime>::SwapNotFound
+		);
+
+		run_to_block(5);
+
+		System::assert_last_event(Event::<TestRuntime>::SwapExpired { swap_id: 1 }.into());
+		assert_eq!(swap_deposit_of(CHARLIE), 0);
+		assert_eq!(PalletBalances::free_balance(CHARLIE), 100);
+		assert!(Swaps::<TestRuntime>::iter().next().is_none());
+		assert!(SwapsByGame::<TestRuntime>::iter().next().is_none());
+	});
+}
+
+#[test]
+fn swap_proposals_are_validated() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		let alices = create_game_for(ALICE);
+		let bobs = create_game_for(BOB);
+		let charlies = create_game_for(CHARLIE);
+		let propose = |who: u64, to: u64, give: &[[u8; 32]], want: &[[u8; 32]], expiry: u64| {
+			PalletGames::propose_swap(
+				RuntimeOrigin::signed(who),
+				to,
+				swap_games(give),
+				swap_games(want),
+				None,
+				expiry,
+			)
+		};
+
+		assert_noop!(
+			propose(ALICE, ALICE, &[alices], &[], 10),
+			Error::<TestRuntime>::TransferToSelf
+		);
+		assert_noop!(propose(ALICE, BOB, &[], &[], 10), Error::<TestRuntime>::EmptySwap);
+		assert_noop!(
+			propose(ALICE, BOB, &[alices], &[bobs], 1),
+			Error::<TestRuntime>::SwapExpiryInPast
+		);
+		assert_noop!(
+			propose(ALICE, BOB, &[alices], &[bobs], 102),
+			Error::<TestRuntime>::SwapExpiryTooFar
+		);
+		assert_noop!(
+			propose(ALICE, BOB, &[alices, alices], &[bobs], 10),
+			Error::<TestRuntime>::DuplicateSwapGame
+		);
+		assert_noop!(propose(ALICE, BOB, &[bobs], &[], 10), Error::<TestRuntime>::NotAuthorized);
+		assert_noop!(
+			propose(ALICE, CHARLIE, &[], &[bobs], 10),
+			Error::<TestRuntime>::NotAuthorized
+		);
+
+		// Charlie cannot cover the deposit.
+		assert_noop!(propose(CHARLIE, ALICE, &[charlies], &[], 10), TokenError::FundsUnavailable);
+
+		for who in [ALICE, BOB, DAVE] {
+			assert_ok!(PalletBalances::mint_into(&who, 100));
+		}
+		assert_ok!(propose(ALICE, BOB, &[alices], &[bobs], 101));
+		assert_ok!(propose(ALICE, CHARLIE, &[alices], &[], 11));
+		assert_noop!(propose(ALICE, DAVE, &[alices], &[], 12), Error::<TestRuntime>::TooManySwaps);
+		assert_ok!(propose(BOB, CHARLIE, &[bobs], &[], 10));
+		// Wanting a game does not use up the slots of the game.
+		assert_ok!(propose(DAVE, ALICE, &[], &[alices], 12));
+		assert_ok!(propose(DAVE, ALICE, &[], &[alices], 13));
+
+		assert_noop!(
+			PalletGames::cancel_swap(RuntimeOrigin::signed(BOB), 0),
+			Error::<TestRuntime>::NotAuthorized
+		);
+		assert_eq!(swap_deposit_of(ALICE), 10);
+		assert_ok!(PalletGames::cancel_swap(RuntimeOrigin::signed(ALICE), 0));
+		System::assert_last_event(Event::<TestRuntime>::SwapCancelled { swap_id: 0 }.into());
+		assert_eq!(swap_deposit_of(ALICE), 5);
+		assert_eq!(SwapsByGame::<TestRuntime>::get(alices).into_inner(), vec![1]);
+		assert_eq!(SwapsByGame::<TestRuntime>::get(bobs).into_inner(), vec![2]);
+		assert_eq!(SwapsExpiringAt::<TestRuntime>::get(10).into_inner(), vec![2]);
+	});
+}
+
+fn bundle_games(ids: &[[u8; 32]]) -> BundleGames<TestRuntime> {
+	ids.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn buying_a_bundle_moves_every_game_for_one_payment() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		assert_ok!(PalletGames::set_protocol_fee(RuntimeOrigin::root(), Permill::from_percent(10)));
+		let games = [create_game_for(ALICE), create_game_for(ALICE), {
+			let id = PalletGames::gen_game_key();
+			assert_ok!(PalletGames::create_game(
+				RuntimeOrigin::signed(CHARLIE),
+				Permill::from_percent(10)
+			));
+			assert_ok!(PalletGames::transfer_game(RuntimeOrigin::signed(CHARLIE), ALICE, id));
+			id
+		}];
+		assert_ok!(PalletGames::set_game_price(
+			RuntimeOrigin::signed(ALICE),
+			games[0],
+			Some(50),
+			None
+		));
+
+		assert_ok!(PalletGames::create_bundle(
+			RuntimeOrigin::signed(ALICE),
+			bundle_games(&games),
+			30
+		));
+		System::assert_last_event(
+			Event::<TestRuntime>::BundleListed {
+				bundle_id: 0,
+				seller: ALICE,
+				games: bundle_games(&games),
+				price: 30,
+			}
+			.into(),
+		);
+		assert_eq!(PalletGames::current_price(games[0]), None);
+		assert_noop!(
+			PalletGames::transfer_game(RuntimeOrigin::signed(ALICE), BOB, games[1]),
+			Error::<TestRuntime>::GameLocked
+		);
+		assert_noop!(
+			PalletGames::set_game_price(RuntimeOrigin::signed(ALICE), games[1], Some(5), None),
+			Error::<TestRuntime>::GameLocked
+		);
+		assert_noop!(
+			PalletGames::create_bundle(RuntimeOrigin::signed(ALICE), bundle_games(&games[..1]), 5),
+			Error::<TestRuntime>::GameLocked
+		);
+		assert_noop!(
+			PalletGames::buy_bundle(RuntimeOrigin::signed(BOB), 0, 29),
+			Error::<TestRuntime>::PriceTooLow
+		);
+		assert_noop!(
+			PalletGames::buy_bundle(RuntimeOrigin::signed(ALICE), 0, 30),
+			Error::<TestRuntime>::TransferToSelf
+		);
+
+		assert_ok!(PalletGames::buy_bundle(RuntimeOrigin::signed(BOB), 0, 30));
+
+		System::assert_last_event(
+			Event::<TestRuntime>::BundleSold {
+				bundle_id: 0,
+				buyer: BOB,
+				games: bundle_games(&games),
+				price: 30,
+			}
+			.into(),
+		);
+		// The fee comes off the whole price and Charlie's royalty off a third of it.
+		System::assert_has_event(
+			Event::<TestRuntime>::BundleProceedsPaid {
+				bundle_id: 0,
+				gross: 30,
+				fee: 3,
+				royalties: vec![(games[2], 1)].try_into().unwrap(),
+				net: 26,
+			}
+			.into(),
+		);
+		assert_eq!(owned_by(BOB).len(), 3);
+		assert!(owned_by(ALICE).is_empty());
+		assert_eq!(PalletBalances::free_balance(BOB), 70);
+		assert_eq!(PalletBalances::free_balance(TREASURY), 3);
+		assert_eq!(PalletBalances::free_balance(CHARLIE), 1);
+		assert_eq!(PalletBalances::free_balance(ALICE), 26);
+		assert!(BundledGames::<TestRuntime>::iter().next().is_none());
+		assert_ok!(PalletGames::transfer_game(RuntimeOrigin::signed(BOB), ALICE, games[0]));
+	});
+}
+
+#[test]
+fn cancelled_bundles_unlock_their_games() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		let alices = [create_game_for(ALICE), create_game_for(ALICE)];
+		let bobs = create_game_for(BOB);
+		assert_noop!(
+			PalletGames::create_bundle(RuntimeOrigin::signed(ALICE), bundle_games(&[]), 10),
+			Error::<TestRuntime>::EmptyBundle
+		);
+		assert_noop!(
+			PalletGames::create_bundle(
+				RuntimeOrigin::signed(ALICE),
+				bundle_games(&[alices[0], alices[0]]),
+				10
+			),
+			Error::<TestRuntime>::DuplicateBundleGame
+		);
+		assert_noop!(
+			PalletGames::create_bundle(
+				RuntimeOrigin::signed(ALICE),
+				bundle_games(&[alices[0], bobs]),
+				10
+			),
+			Error::<TestRuntime>::NotAuthorized
+		);
+		assert_ok!(PalletGames::create_bundle(
+			RuntimeOrigin::signed(ALICE),
+			bundle_games(&alices),
+			10
+		));
+		assert_noop!(
+			PalletGames::cancel_bundle(RuntimeOrigin::signed(BOB), 0),
+			Error::<TestRuntime>::NotAuthorized
+		);
+
+		assert_ok!(PalletGames::cancel_bundle(RuntimeOrigin::signed(ALICE), 0));
+
+		System::assert_last_event(Event::<TestRuntime>::BundleCancelled { bundle_id: 0 }.into());
+		assert_noop!(
+			PalletGames::buy_bundle(RuntimeOrigin::signed(BOB), 0, 10),
+			Error::<TestRuntime>::BundleNotFound
+		);
+		assert_ok!(PalletGames::transfer_game(RuntimeOrigin::signed(ALICE), BOB, alices[0]));
+		assert_ok!(PalletGames::set_game_price(
+			RuntimeOrigin::signed(ALICE),
+			alices[1],
+			Some(5),
+			None
+		));
+	});
+}
+
+fn order(id: [u8; 32], price: u64, expiry: u64, nonce: u32) -> OrderOf<TestRuntime> {
+	Order { id, price, expiry, nonce }
+}
+
+fn sign(signer: u64, order: &OrderOf<TestRuntime>) -> TestSignature {
+	TestSignature(signer, PalletGames::order_payload(order))
+}
+
+#[test]
+fn signed_orders_can_be_filled_once() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		let id = create_game_for(ALICE);
+		let signed = order(id, 10, 5, 0);
+
+		assert_ok!(PalletGames::fill_order(
+			RuntimeOrigin::signed(BOB),
+			signed.clone(),
+			sign(ALICE, &signed)
+		));
+
+		System::assert_last_event(
+			Event::<TestRuntime>::OrderFilled { id, seller: ALICE, buyer: BOB, price: 10 }.into(),
+		);
+		assert_eq!(Games::<TestRuntime>::get(id).unwrap().owner, BOB);
+		assert_eq!(PalletBalances::free_balance(ALICE), 10);
+		assert_eq!(PalletBalances::free_balance(BOB), 90);
+
+		// Once the game is back with Alice, her old order is still correctly signed but closed.
+		assert_ok!(PalletGames::transfer_game(RuntimeOrigin::signed(BOB), ALICE, id));
+		assert_noop!(
+			PalletGames::fill_order(
+				RuntimeOrigin::signed(BOB),
+				signed.clone(),
+				sign(ALICE, &signed)
+			),
+			Error::<TestRuntime>::OrderClosed
+		);
+	});
+}
+
+#[test]
+fn orders_may_expire_far_ahead() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&ALICE, 100));
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		let id = create_game_for(ALICE);
+		let kept = order(id, 10, u64::MAX, 0);
+		let cancelled = order(id, 20, u64::MAX, 0);
+
+		assert_ok!(PalletGames::cancel_order(RuntimeOrigin::signed(ALICE), cancelled.clone()));
+		assert_noop!(
+			PalletGames::fill_order(
+				RuntimeOrigin::signed(BOB),
+				cancelled.clone(),
+				sign(ALICE, &cancelled)
+			),
+			Error::<TestRuntime>::OrderClosed
+		);
+		assert_ok!(PalletGames::fill_order(
+			RuntimeOrigin::signed(BOB),
+			kept.clone(),
+			sign(ALICE, &kept)
+		));
+		assert_eq!(Games::<TestRuntime>::get(id).unwrap().owner, BOB);
+	});
+}
+
+#[test]
+fn signed_orders_are_validated_and_can_be_cancelled() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&ALICE, 100));
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		let id = create_game_for(ALICE);
+		let signed = order(id, 10, 5, 0);
+		let fill = |order: &OrderOf<TestRuntime>, signature| {
+			PalletGames::fill_order(RuntimeOrigin::signed(BOB), order.clone(), signature)
+		};
+
+		assert_noop!(
+			fill(&order([9; 32], 10, 5, 0), sign(ALICE, &signed)),
+			Error::<TestRuntime>::GameNotFound
+		);
+		assert_noop!(
+			PalletGames::fill_order(
+				RuntimeOrigin::signed(ALICE),
+				signed.clone(),
+				sign(ALICE, &signed)
+			),
+			Error::<TestRuntime>::TransferToSelf
+		);
+		assert_noop!(
+			fill(&signed, sign(BOB, &signed)),
+			Error::<TestRuntime>::InvalidOrderSignature
+		);
+		assert_noop!(
+			fill(&order(id, 1, 5, 0), sign(ALICE, &signed)),
+			Error::<TestRuntime>::InvalidOrderSignature
+		);
+		assert_noop!(
+			fill(&order(id, 10, 5, 1), sign(ALICE, &order(id, 10, 5, 1))),
+			Error::<TestRuntime>::StaleOrderNonce
+		);
+		// Signatures are bound to this chain's genesis hash.
+		let elsewhere = TestSignature(ALICE, (b"game_order", H256::zero(), &signed).encode());
+		assert_noop!(fill(&signed, elsewhere), Error::<TestRuntime>::InvalidOrderSignature);
+		run_to_block(5);
+		assert_noop!(fill(&signed, sign(ALICE, &signed)), Error::<TestRuntime>::OrderExpired);
+
+		let signed = order(id, 10, 10, 0);
+		assert_ok!(PalletGames::cancel_order(RuntimeOrigin::signed(ALICE), signed.clone()));
+		System::assert_last_event(
+			Event::<TestRuntime>::OrderCancelled {
+				seller: ALICE,
+				order_hash: PalletGames::order_hash(&signed),
+			}
+			.into(),
+		);
+		assert_noop!(fill(&signed, sign(ALICE, &signed)), Error::<TestRuntime>::OrderClosed);
+		assert_noop!(
+			PalletGames::cancel_order(RuntimeOrigin::signed(ALICE), signed.clone()),
+			Error::<TestRuntime>::OrderClosed
+		);
+
+		let other = order(id, 20, 10, 0);
+		assert_ok!(PalletGames::bump_order_nonce(RuntimeOrigin::signed(ALICE)));
+		System::assert_last_event(
+			Event::<TestRuntime>::OrderNonceBumped { seller: ALICE, nonce: 1 }.into(),
+		);
+		assert_noop!(fill(&other, sign(ALICE, &other)), Error::<TestRuntime>::StaleOrderNonce);
+		let renewed = order(id, 20, 10, 1);
+		assert_ok!(fill(&renewed, sign(ALICE, &renewed)));
+		assert_eq!(Games::<TestRuntime>::get(id).unwrap().owner, BOB);
+	});
+}
+
+#[test]
+fn closed_orders_are_forgotten_once_they_expire() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&ALICE, 100));
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		assert_ok!(PalletBalances::mint_into(&DAVE, 100));
+		let id = create_game_for(ALICE);
+		let cancel = |who, price| {
+			PalletGames::cancel_order(RuntimeOrigin::signed(who), order(id, price, 5, 0))
+		};
+
+		// Only the owner of the game can cancel orders for it.
+		assert_noop!(cancel(DAVE, 10), Error::<TestRuntime>::NotAuthorized);
+		// However many orders are cancelled, other orders expiring in the same block still fill.
+		for price in [10, 20, 30] {
+			assert_ok!(cancel(ALICE, price));
+		}
+		assert_eq!(
+			PalletBalances::balance_on_hold(&HoldReason::OrderCancellation.into(), &ALICE),
+			9
+		);
+		let signed = order(id, 40, 5, 0);
+		assert_ok!(PalletGames::fill_order(
+			RuntimeOrigin::signed(BOB),
+			signed.clone(),
+			sign(ALICE, &signed)
+		));
+		assert_eq!(ClosedOrders::<TestRuntime>::iter_prefix(ALICE).count(), 4);
+
+		run_to_block(5);
+		let per_order = <TestRuntime as Config>::WeightInfo::expire_closed_order();
+		let db: frame_support::weights::RuntimeDbWeight =
+			<TestRuntime as frame_system::Config>::DbWeight::get();
+		// Room for the empty blocks 1 to 4 and two of the orders expiring at block 5.
+		let budget = db
+			.reads_writes(1, 1)
+			.saturating_add(db.reads(4))
+			.saturating_add(per_order.saturating_mul(2));
+		assert_eq!(PalletGames::sweep_closed_orders(5, Weight::zero()), Weight::zero());
+		PalletGames::sweep_closed_orders(5, budget);
+		assert_eq!(ClosedOrders::<TestRuntime>::iter_prefix(ALICE).count(), 2);
+
+		PalletGames::on_idle(5, Weight::MAX);
+		assert!(ClosedOrders::<TestRuntime>::iter().next().is_none());
+		assert_eq!(ClosedOrdersExpiringAt::<TestRuntime>::iter_prefix(5).count(), 0);
+		assert_eq!(OrderSweepCursor::<TestRuntime>::get(), Some(6));
+		assert_eq!(
+			PalletBalances::balance_on_hold(&HoldReason::OrderCancellation.into(), &ALICE),
+			0
+		);
+		// Expired orders can no longer be filled or cancelled.
+		assert_noop!(cancel(BOB, 10), Error::<TestRuntime>::OrderExpired);
+	});
+}
+
+fn issue_soulbound_for(owner: u64) -> [u8; 32] {
+	let id = PalletGames::gen_game_key();
+	assert_ok!(PalletGames::issue_soulbound_game(RuntimeOrigin::root(), owner));
+	id
+}
+
+#[test]
+fn only_the_issuer_can_mint_soulbound_games() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			PalletGames::issue_soulbound_game(RuntimeOrigin::signed(ALICE), ALICE),
+			DispatchError::BadOrigin
+		);
+
+		let id = issue_soulbound_for(ALICE);
+
+		System::assert_last_event(Event::<TestRuntime>::GameCreated { owner: ALICE, id }.into());
+		let game = Games::<TestRuntime>::get(id).unwrap();
+		assert_eq!((game.owner, game.creator), (ALICE, ALICE));
+		assert!(!game.transferable);
+		assert!(Games::<TestRuntime>::get(create_game_for(ALICE)).unwrap().transferable);
+	});
+}
+
+#[test]
+fn soulbound_games_cannot_change_hands_but_can_be_burned() {
+	build_and_execute(|| {
+		System::set_block_number(1);
+		assert_ok!(PalletBalances::mint_into(&BOB, 100));
+		let id = issue_soulbound_for(ALICE);
+		let bobs = create_game_for(BOB);
+		let alice = RuntimeOrigin::signed(ALICE);
+
+		assert_noop!(
+			PalletGames::transfer_game(alice.clone(), BOB, id),
+			Error::<TestRuntime>::Soulbound
+		);
+		assert_ok!(PalletGames::approve(alice.clone(), id, Some(CHARLIE)));
+		assert_noop!(
+			PalletGames::transfer_from(RuntimeOrigin::signed(CHARLIE), ALICE, BOB, id),
+			Error::<TestRuntime>::Soulbound
+		);
+		assert_noop!(
+			PalletGames::set_game_price(alice.clone(), id, Some(10), None),
+			Error::<TestRuntime>::Soulbound
+		);
+		assert_ok!(PalletGames::set_game_price(alice.clone(), id, None, None));
+		assert_noop!(
+			PalletGames::create_auction(alice.clone(), id, 10, 5),
+			Error::<TestRuntime>::Soulbound
+		);
+		assert_noop!(
+			PalletGames::create_dutch_auction(alice.clone(), id, 10, 1, 5),
+			Error::<TestRuntime>::Soulbound
+		);
+		assert_noop!(
+			PalletGames::create_sealed_auction(alice.clone(), id, 10, 3, 5),
+			Error::<TestRuntime>::Soulbound
+		);
+		assert_noop!(
+			PalletGames::make_offer(RuntimeOrigin::signed(BOB), id, 10, 5),
+			Error::<TestRuntime>::Soulbound
+		);
+		assert_noop!(
+			PalletGames::propose_swap(
+				alice.clone(),
+				BOB,
+				swap_games(&[id]),
+				swap_games(&[bobs]),
+				None,
+				5
+			),
+			Error::<TestRuntime>::Soulbound
+		);
+		assert_noop!(
+			PalletGames::propose_swap(
+				RuntimeOrigin::signed(BOB),
+				ALICE,
+				swap_games(&[bobs]),
+				swap_games(&[id]),
+				None,
+				5
+			),
+			Error::<TestRuntime>::Soulbound
+		);
+		assert_noop!(
+			PalletGames::create_bundle(alice.clone(), bundle_games(&[id]), 10),
+			Error::<TestRuntime>::Soulbound
+		);
+		assert_noop!(
+			PalletGames::lend_game(alice.clone(), id, BOB, 5, None),
+			Error::<TestRuntime>::Soulbound
+		);
+		assert_noop!(
+			PalletGames::lend_game(alice.clone(), id, BOB, 5, Some(10)),
+			Error::<TestRuntime>::Soulbound
+		);
+		let signed = order(id, 10, 5, 0);
+		assert_noop!(
+			PalletGames::fill_order(
+				RuntimeOrigin::signed(BOB),
+				signed.clone(),
+				sign(ALICE, &signed)
+			),
+			Error::<TestRuntime>::Soulbound
+		);
+
+		assert_ok!(PalletGames::burn_game(alice, id));
+		assert!(!Games::<TestRuntime>::contains_key(id));
+	});
+}
@@ -0,0 +1,34 @@
+//! Types returned to clients by the marketplace runtime API.
+//!
+//! They are kept apart from the storage layout so that a storage migration does not change what
+//! clients decode.
+
+use alloc::vec::Vec;
+use frame::arithmetic::Permill;
+use frame::prelude::*;
+
+/// A game as reported to clients.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct GameInfo<AccountId, Balance> {
+	pub id: [u8; 32],
+	pub owner: AccountId,
+	pub creator: AccountId,
+	pub royalty: Permill,
+	/// The price a buyer would pay right now, or `None` if the game is not for sale.
+	pub price: Option<Balance>,
+}
+
+/// A page of games for sale. `next` is set when there are games left to read; pass it back to
+/// continue after the last game this page read.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Listings<AccountId, Balance> {
+	pub games: Vec<GameInfo<AccountId, Balance>>,
+	pub next: Option<[u8; 32]>,
+}
+
+/// How many games have been minted, and how many of them still exist.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Supply {
+	pub total: u32,
+	pub live: u32,
+}
@@ -1,90 +1,2302 @@
 
-//! Autogenerated weights for pallet_template
+//! Autogenerated weights for `pallet_template`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-04-06, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 43.0.0
+//! DATE: 2026-10-18, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `Alexs-MacBook-Pro-2.local`, CPU: `<UNKNOWN>`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
 
 // Executed Command:
-// ../../target/release/node-template
-// benchmark
+// /root/target-bencher/release/bencher
 // pallet
-// --chain
-// dev
-// --pallet
-// pallet_template
-// --extrinsic
-// *
+// --chain=dev
+// --pallet=pallet_template
+// --extrinsic=*
 // --steps=50
 // --repeat=20
 // --wasm-execution=compiled
-// --output
-// pallets/template/src/weights.rs
+// --output=pallets/template/src/weights.rs
 // --template
-// ../../.maintain/frame-weight-template.hbs
+// ./.maintain/frame-weight-template.hbs
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
 #![allow(unused_imports)]
+#![allow(missing_docs)]
 
 use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use core::marker::PhantomData;
 
-/// Weight functions needed for pallet_template.
+/// Weight functions needed for `pallet_template`.
 pub trait WeightInfo {
-	fn do_something() -> Weight;
-	fn cause_error() -> Weight;
+	fn create_game() -> Weight;
+	fn transfer_game() -> Weight;
+	fn set_game_price() -> Weight;
+	fn buy_game() -> Weight;
+	fn create_auction() -> Weight;
+	fn bid() -> Weight;
+	fn cancel_auction() -> Weight;
+	fn settle_auction() -> Weight;
+	fn create_dutch_auction() -> Weight;
+	fn make_offer() -> Weight;
+	fn withdraw_offer() -> Weight;
+	fn accept_offer() -> Weight;
+	fn expire_offers(n: u32, ) -> Weight;
+	fn expire_listings(n: u32, ) -> Weight;
+	fn set_protocol_fee() -> Weight;
+	fn set_metadata() -> Weight;
+	fn clear_metadata() -> Weight;
+	fn lock_metadata() -> Weight;
+	fn approve() -> Weight;
+	fn set_operator() -> Weight;
+	fn transfer_from() -> Weight;
+	fn burn_game() -> Weight;
+	fn lend_game() -> Weight;
+	fn accept_rental() -> Weight;
+	fn create_games(n: u32, ) -> Weight;
+	fn transfer_games(n: u32, ) -> Weight;
+	fn create_sealed_auction() -> Weight;
+	fn commit_bid() -> Weight;
+	fn reveal_bid() -> Weight;
+	fn settle_sealed_auction() -> Weight;
+	fn propose_swap(n: u32, ) -> Weight;
+	fn accept_swap() -> Weight;
+	fn cancel_swap() -> Weight;
+	fn expire_swaps(n: u32, ) -> Weight;
+	fn create_bundle(n: u32, ) -> Weight;
+	fn cancel_bundle() -> Weight;
+	fn buy_bundle() -> Weight;
+	fn fill_order() -> Weight;
+	fn cancel_order() -> Weight;
+	fn bump_order_nonce() -> Weight;
+	fn expire_closed_order() -> Weight;
+	fn issue_soulbound_game() -> Weight;
 }
 
-/// Weights for pallet_template using the Substrate node and recommended hardware.
+/// Weights for `pallet_template` using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	/// Storage: TemplateModule Something (r:0 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn do_something() -> Weight {
+	/// Storage: `GamePallet::GameCount` (r:1 w:1)
+	/// Proof: `GamePallet::GameCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `RandomnessCollectiveFlip::RandomMaterial` (r:1 w:0)
+	/// Proof: `RandomnessCollectiveFlip::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 3089, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Games` (r:1 w:1)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::LiveGameCount` (r:1 w:1)
+	/// Proof: `GamePallet::LiveGameCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:1)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	fn create_game() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `284`
+		//  Estimated: `4079`
+		// Minimum execution time: 44_148_000 picoseconds.
+		Weight::from_parts(64_368_000, 4079)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `GamePallet::Auctions` (r:1 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Games` (r:1 w:1)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:1 w:1)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::ListingsExpiringAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Offers` (r:1 w:1)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OffersExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:10 w:10)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:11 w:11)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:21 w:21)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Swaps` (r:5 w:5)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:1 w:0)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Approvals` (r:0 w:1)
+	/// Proof: `GamePallet::Approvals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:2)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::RentalOffers` (r:0 w:1)
+	/// Proof: `GamePallet::RentalOffers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn transfer_game() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7959`
+		//  Estimated: `54414`
+		// Minimum execution time: 1_250_786_000 picoseconds.
+		Weight::from_parts(1_746_960_000, 54414)
+			.saturating_add(T::DbWeight::get().reads(58_u64))
+			.saturating_add(T::DbWeight::get().writes(57_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:1)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:1 w:1)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingsExpiringAt` (r:2 w:2)
+	/// Proof: `GamePallet::ListingsExpiringAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingSweepCursor` (r:1 w:0)
+	/// Proof: `GamePallet::ListingSweepCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_game_price() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3773`
+		//  Estimated: `9166`
+		// Minimum execution time: 79_363_000 picoseconds.
+		Weight::from_parts(115_949_000, 9166)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:1)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:1)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:13 w:13)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ProtocolFee` (r:1 w:0)
+	/// Proof: `GamePallet::ProtocolFee` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:1 w:0)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Offers` (r:1 w:1)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OffersExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:11 w:11)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:21 w:21)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Swaps` (r:5 w:5)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:1 w:0)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Approvals` (r:0 w:1)
+	/// Proof: `GamePallet::Approvals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:2)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::RentalOffers` (r:0 w:1)
+	/// Proof: `GamePallet::RentalOffers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn buy_game() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8518`
+		//  Estimated: `54414`
+		// Minimum execution time: 1_439_137_000 picoseconds.
+		Weight::from_parts(2_578_003_000, 54414)
+			.saturating_add(T::DbWeight::get().reads(61_u64))
+			.saturating_add(T::DbWeight::get().writes(59_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:0)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:1)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::AuctionsEndingAt` (r:1 w:1)
+	/// Proof: `GamePallet::AuctionsEndingAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	fn create_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3212`
+		//  Estimated: `5078`
+		// Minimum execution time: 99_726_000 picoseconds.
+		Weight::from_parts(129_321_000, 5078)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `GamePallet::Auctions` (r:1 w:1)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	fn bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `613`
+		//  Estimated: `6254`
+		// Minimum execution time: 146_100_000 picoseconds.
+		Weight::from_parts(161_296_000, 6254)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:1)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::AuctionsEndingAt` (r:1 w:1)
+	/// Proof: `GamePallet::AuctionsEndingAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	fn cancel_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2572`
+		//  Estimated: `5078`
+		// Minimum execution time: 96_288_000 picoseconds.
+		Weight::from_parts(141_698_000, 5078)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `GamePallet::AuctionsEndingAt` (r:1 w:1)
+	/// Proof: `GamePallet::AuctionsEndingAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:1)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Games` (r:1 w:1)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:14 w:13)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ProtocolFee` (r:1 w:0)
+	/// Proof: `GamePallet::ProtocolFee` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:12 w:12)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:1 w:0)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Offers` (r:1 w:1)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OffersExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:21 w:21)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Swaps` (r:5 w:5)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:1 w:0)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Approvals` (r:0 w:1)
+	/// Proof: `GamePallet::Approvals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:2)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::RentalOffers` (r:0 w:1)
+	/// Proof: `GamePallet::RentalOffers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn settle_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `13034`
+		//  Estimated: `54414`
+		// Minimum execution time: 1_617_916_000 picoseconds.
+		Weight::from_parts(2_585_976_000, 54414)
+			.saturating_add(T::DbWeight::get().reads(64_u64))
+			.saturating_add(T::DbWeight::get().writes(61_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:0)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:1)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn create_dutch_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `478`
+		//  Estimated: `3668`
+		// Minimum execution time: 57_646_000 picoseconds.
+		Weight::from_parts(74_884_000, 3668)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:0)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Offers` (r:1 w:1)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OffersExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn make_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2205`
+		//  Estimated: `6678`
+		// Minimum execution time: 179_588_000 picoseconds.
+		Weight::from_parts(280_591_000, 6678)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `GamePallet::Offers` (r:1 w:1)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OffersExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	fn withdraw_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1919`
+		//  Estimated: `6678`
+		// Minimum execution time: 105_938_000 picoseconds.
+		Weight::from_parts(168_214_000, 6678)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:1)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Offers` (r:1 w:1)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OffersExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:12 w:12)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ProtocolFee` (r:1 w:0)
+	/// Proof: `GamePallet::ProtocolFee` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:11 w:11)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:1 w:0)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:21 w:21)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Swaps` (r:5 w:5)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:1 w:0)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Approvals` (r:0 w:1)
+	/// Proof: `GamePallet::Approvals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:2)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::RentalOffers` (r:0 w:1)
+	/// Proof: `GamePallet::RentalOffers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn accept_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8275`
+		//  Estimated: `54414`
+		// Minimum execution time: 1_455_489_000 picoseconds.
+		Weight::from_parts(2_378_366_000, 54414)
+			.saturating_add(T::DbWeight::get().reads(60_u64))
+			.saturating_add(T::DbWeight::get().writes(57_u64))
+	}
+	/// Storage: `GamePallet::OffersExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Offers` (r:50 w:50)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:50 w:50)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:50 w:50)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 50]`.
+	fn expire_offers(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `154 + n * (375 ±0)`
+		//  Estimated: `6678 + n * (3044 ±0)`
+		// Minimum execution time: 9_494_000 picoseconds.
+		Weight::from_parts(153_567_880, 6678)
+			// Standard Error: 1_642_382
+			.saturating_add(Weight::from_parts(75_263_417, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3044).saturating_mul(n.into()))
+	}
+	/// Storage: `GamePallet::ListingsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::ListingsExpiringAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Games` (r:50 w:50)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:0 w:50)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 50]`.
+	fn expire_listings(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `309 + n * (208 ±0)`
+		//  Estimated: `5078 + n * (2678 ±0)`
+		// Minimum execution time: 5_516_000 picoseconds.
+		Weight::from_parts(5_890_000, 5078)
+			// Standard Error: 165_778
+			.saturating_add(Weight::from_parts(21_879_929, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2678).saturating_mul(n.into()))
+	}
+	/// Storage: `GamePallet::ProtocolFee` (r:0 w:1)
+	/// Proof: `GamePallet::ProtocolFee` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_protocol_fee() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+		// Minimum execution time: 9_119_000 picoseconds.
+		Weight::from_parts(10_055_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: TemplateModule Something (r:1 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
+	/// Storage: `GamePallet::Games` (r:1 w:0)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:1 w:1)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	fn set_metadata() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+		//  Measured:  `5370`
+		//  Estimated: `7983`
+		// Minimum execution time: 171_105_000 picoseconds.
+		Weight::from_parts(178_510_000, 7983)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:0)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:1 w:1)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	fn clear_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5200`
+		//  Estimated: `7983`
+		// Minimum execution time: 102_602_000 picoseconds.
+		Weight::from_parts(107_854_000, 7983)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:0)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:1 w:1)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	fn lock_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5070`
+		//  Estimated: `7983`
+		// Minimum execution time: 68_227_000 picoseconds.
+		Weight::from_parts(77_186_000, 7983)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:0)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Operators` (r:1 w:0)
+	/// Proof: `GamePallet::Operators` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Approvals` (r:0 w:1)
+	/// Proof: `GamePallet::Approvals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn approve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `541`
+		//  Estimated: `3668`
+		// Minimum execution time: 45_226_000 picoseconds.
+		Weight::from_parts(47_125_000, 3668)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `GamePallet::Operators` (r:0 w:1)
+	/// Proof: `GamePallet::Operators` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	fn set_operator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 17_035_000 picoseconds.
+		Weight::from_parts(17_921_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `GamePallet::Approvals` (r:1 w:1)
+	/// Proof: `GamePallet::Approvals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Operators` (r:1 w:0)
+	/// Proof: `GamePallet::Operators` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Games` (r:1 w:1)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:1 w:1)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::ListingsExpiringAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Offers` (r:1 w:1)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OffersExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:11 w:11)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:11 w:11)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:21 w:21)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Swaps` (r:5 w:5)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:1 w:0)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:2)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::RentalOffers` (r:0 w:1)
+	/// Proof: `GamePallet::RentalOffers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn transfer_from() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8330`
+		//  Estimated: `54414`
+		// Minimum execution time: 1_859_928_000 picoseconds.
+		Weight::from_parts(2_074_784_000, 54414)
+			.saturating_add(T::DbWeight::get().reads(61_u64))
+			.saturating_add(T::DbWeight::get().writes(58_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:1)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Approvals` (r:1 w:1)
+	/// Proof: `GamePallet::Approvals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Operators` (r:1 w:0)
+	/// Proof: `GamePallet::Operators` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::LiveGameCount` (r:1 w:1)
+	/// Proof: `GamePallet::LiveGameCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:1 w:0)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Offers` (r:1 w:1)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OffersExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:11 w:11)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:11 w:11)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:21 w:21)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Swaps` (r:5 w:5)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:1 w:1)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:1)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::RentalOffers` (r:0 w:1)
+	/// Proof: `GamePallet::RentalOffers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn burn_game() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `12892`
+		//  Estimated: `54414`
+		// Minimum execution time: 1_278_134_000 picoseconds.
+		Weight::from_parts(2_086_410_000, 54414)
+			.saturating_add(T::DbWeight::get().reads(61_u64))
+			.saturating_add(T::DbWeight::get().writes(57_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:1)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:1 w:1)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::ListingsExpiringAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::RentalOffers` (r:0 w:1)
+	/// Proof: `GamePallet::RentalOffers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn lend_game() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2180`
+		//  Estimated: `5078`
+		// Minimum execution time: 89_019_000 picoseconds.
+		Weight::from_parts(95_604_000, 5078)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `GamePallet::RentalOffers` (r:1 w:1)
+	/// Proof: `GamePallet::RentalOffers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Games` (r:1 w:1)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:1 w:1)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::ListingsExpiringAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	fn accept_rental() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2304`
+		//  Estimated: `5078`
+		// Minimum execution time: 196_279_000 picoseconds.
+		Weight::from_parts(206_805_000, 5078)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `GamePallet::GameCount` (r:1 w:1)
+	/// Proof: `GamePallet::GameCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `RandomnessCollectiveFlip::RandomMaterial` (r:1 w:0)
+	/// Proof: `RandomnessCollectiveFlip::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 3089, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Games` (r:50 w:50)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::LiveGameCount` (r:1 w:1)
+	/// Proof: `GamePallet::LiveGameCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:50)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 50]`.
+	fn create_games(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `369 + n * (1 ±0)`
+		//  Estimated: `4079 + n * (2678 ±0)`
+		// Minimum execution time: 60_400_000 picoseconds.
+		Weight::from_parts(5_353_374, 4079)
+			// Standard Error: 163_306
+			.saturating_add(Weight::from_parts(31_412_115, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2678).saturating_mul(n.into()))
+	}
+	/// Storage: `GamePallet::Auctions` (r:50 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:50 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:50 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:50 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Games` (r:50 w:50)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:50 w:50)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingsExpiringAt` (r:50 w:50)
+	/// Proof: `GamePallet::ListingsExpiringAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Offers` (r:50 w:50)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OffersExpiringAt` (r:50 w:50)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:10 w:10)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:11 w:11)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:1050 w:1050)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Swaps` (r:250 w:250)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:50 w:50)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:50 w:0)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Approvals` (r:0 w:50)
+	/// Proof: `GamePallet::Approvals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:100)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::RentalOffers` (r:0 w:50)
+	/// Proof: `GamePallet::RentalOffers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 50]`.
+	fn transfer_games(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3931 + n * (5272 ±0)`
+		//  Estimated: `29942 + n * (53424 ±0)`
+		// Minimum execution time: 1_909_867_000 picoseconds.
+		Weight::from_parts(1_970_096_000, 29942)
+			// Standard Error: 16_706_557
+			.saturating_add(Weight::from_parts(1_770_806_207, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(21_u64))
+			.saturating_add(T::DbWeight::get().reads((37_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(21_u64))
+			.saturating_add(T::DbWeight::get().writes((36_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 53424).saturating_mul(n.into()))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:0)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:1)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctionsEndingAt` (r:1 w:1)
+	/// Proof: `GamePallet::SealedAuctionsEndingAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	fn create_sealed_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3725`
+		//  Estimated: `5078`
+		// Minimum execution time: 98_904_000 picoseconds.
+		Weight::from_parts(108_368_000, 5078)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedBids` (r:1 w:1)
+	/// Proof: `GamePallet::SealedBids` (`max_values`: None, `max_size`: Some(1669), added: 4144, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	fn commit_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2369`
+		//  Estimated: `5134`
+		// Minimum execution time: 111_337_000 picoseconds.
+		Weight::from_parts(126_619_000, 5134)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:1)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedBids` (r:1 w:1)
+	/// Proof: `GamePallet::SealedBids` (`max_values`: None, `max_size`: Some(1669), added: 4144, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	fn reveal_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2777`
+		//  Estimated: `5134`
+		// Minimum execution time: 99_858_000 picoseconds.
+		Weight::from_parts(110_744_000, 5134)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `GamePallet::SealedAuctionsEndingAt` (r:1 w:1)
+	/// Proof: `GamePallet::SealedAuctionsEndingAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:1)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedBids` (r:1 w:1)
+	/// Proof: `GamePallet::SealedBids` (`max_values`: None, `max_size`: Some(1669), added: 4144, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:33 w:32)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:31 w:31)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Games` (r:1 w:1)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ProtocolFee` (r:1 w:0)
+	/// Proof: `GamePallet::ProtocolFee` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:1 w:0)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Offers` (r:1 w:1)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OffersExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:21 w:21)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Swaps` (r:5 w:5)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:1 w:0)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Approvals` (r:0 w:1)
+	/// Proof: `GamePallet::Approvals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:2)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::RentalOffers` (r:0 w:1)
+	/// Proof: `GamePallet::RentalOffers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn settle_sealed_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `17461`
+		//  Estimated: `86889`
+		// Minimum execution time: 2_208_569_000 picoseconds.
+		Weight::from_parts(2_968_890_000, 86889)
+			.saturating_add(T::DbWeight::get().reads(103_u64))
+			.saturating_add(T::DbWeight::get().writes(100_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:10 w:0)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::NextSwapId` (r:1 w:1)
+	/// Proof: `GamePallet::NextSwapId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:5 w:5)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Swaps` (r:0 w:1)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 10]`.
+	fn propose_swap(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `715 + n * (197 ±0)`
+		//  Estimated: `4599 + n * (2678 ±0)`
+		// Minimum execution time: 107_277_000 picoseconds.
+		Weight::from_parts(145_502_363, 4599)
+			// Standard Error: 583_902
+			.saturating_add(Weight::from_parts(23_517_949, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(Weight::from_parts(0, 2678).saturating_mul(n.into()))
+	}
+	/// Storage: `GamePallet::Swaps` (r:41 w:41)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:170 w:170)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:41 w:41)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:11 w:11)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:12 w:12)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:10 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:10 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:10 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:10 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Games` (r:10 w:10)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:10 w:0)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Offers` (r:10 w:10)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OffersExpiringAt` (r:10 w:10)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:10 w:0)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Approvals` (r:0 w:10)
+	/// Proof: `GamePallet::Approvals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:20)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::RentalOffers` (r:0 w:10)
+	/// Proof: `GamePallet::RentalOffers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn accept_swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `48620`
+		//  Estimated: `433470`
+		// Minimum execution time: 17_599_172_000 picoseconds.
+		Weight::from_parts(18_876_450_000, 433470)
+			.saturating_add(T::DbWeight::get().reads(365_u64))
+			.saturating_add(T::DbWeight::get().writes(345_u64))
+	}
+	/// Storage: `GamePallet::Swaps` (r:1 w:1)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:5 w:5)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	fn cancel_swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1297`
+		//  Estimated: `13710`
+		// Minimum execution time: 211_046_000 picoseconds.
+		Weight::from_parts(230_168_000, 13710)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Swaps` (r:50 w:50)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:250 w:250)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:50 w:50)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:50 w:50)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 50]`.
+	fn expire_swaps(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `232 + n * (926 ±0)`
+		//  Estimated: `3678 + n * (12720 ±0)`
+		// Minimum execution time: 8_135_000 picoseconds.
+		Weight::from_parts(8_867_000, 3678)
+			// Standard Error: 1_551_468
+			.saturating_add(Weight::from_parts(174_919_705, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((8_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 12720).saturating_mul(n.into()))
+	}
+	/// Storage: `GamePallet::Games` (r:10 w:10)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:10 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:10 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:10 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:10 w:10)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:10 w:10)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::ListingsExpiringAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::NextBundleId` (r:1 w:1)
+	/// Proof: `GamePallet::NextBundleId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Bundles` (r:0 w:1)
+	/// Proof: `GamePallet::Bundles` (`max_values`: None, `max_size`: Some(381), added: 2856, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 10]`.
+	fn create_bundle(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `347 + n * (265 ±0)`
+		//  Estimated: `5078 + n * (2678 ±0)`
+		// Minimum execution time: 123_151_000 picoseconds.
+		Weight::from_parts(66_030_506, 5078)
+			// Standard Error: 739_743
+			.saturating_add(Weight::from_parts(93_644_361, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2678).saturating_mul(n.into()))
+	}
+	/// Storage: `GamePallet::Bundles` (r:1 w:1)
+	/// Proof: `GamePallet::Bundles` (`max_values`: None, `max_size`: Some(381), added: 2856, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:0 w:10)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn cancel_bundle() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `557`
+		//  Estimated: `3846`
+		// Minimum execution time: 92_493_000 picoseconds.
+		Weight::from_parts(119_241_000, 3846)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+	/// Storage: `GamePallet::Bundles` (r:1 w:1)
+	/// Proof: `GamePallet::Bundles` (`max_values`: None, `max_size`: Some(381), added: 2856, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Games` (r:10 w:10)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ProtocolFee` (r:1 w:0)
+	/// Proof: `GamePallet::ProtocolFee` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:22 w:22)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:10 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:10 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:10 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:10 w:0)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Offers` (r:10 w:10)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OffersExpiringAt` (r:10 w:10)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:11 w:11)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:210 w:210)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Swaps` (r:50 w:50)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:10 w:10)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:10 w:0)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Approvals` (r:0 w:10)
+	/// Proof: `GamePallet::Approvals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:0 w:10)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:20)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::RentalOffers` (r:0 w:10)
+	/// Proof: `GamePallet::RentalOffers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn buy_bundle() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `55718`
+		//  Estimated: `535230`
+		// Minimum execution time: 11_669_623_000 picoseconds.
+		Weight::from_parts(15_537_662_000, 535230)
+			.saturating_add(T::DbWeight::get().reads(385_u64))
+			.saturating_add(T::DbWeight::get().writes(384_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:1)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OrderNonces` (r:1 w:0)
+	/// Proof: `GamePallet::OrderNonces` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ClosedOrders` (r:1 w:1)
+	/// Proof: `GamePallet::ClosedOrders` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OrderSweepCursor` (r:1 w:1)
+	/// Proof: `GamePallet::OrderSweepCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:13 w:13)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ProtocolFee` (r:1 w:0)
+	/// Proof: `GamePallet::ProtocolFee` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:1 w:1)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::ListingsExpiringAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Offers` (r:1 w:1)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OffersExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:11 w:11)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:21 w:21)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Swaps` (r:5 w:5)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:1 w:0)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ClosedOrdersExpiringAt` (r:0 w:1)
+	/// Proof: `GamePallet::ClosedOrdersExpiringAt` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Approvals` (r:0 w:1)
+	/// Proof: `GamePallet::Approvals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:2)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::RentalOffers` (r:0 w:1)
+	/// Proof: `GamePallet::RentalOffers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn fill_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8455`
+		//  Estimated: `54414`
+		// Minimum execution time: 2_431_608_000 picoseconds.
+		Weight::from_parts(2_701_319_000, 54414)
+			.saturating_add(T::DbWeight::get().reads(66_u64))
+			.saturating_add(T::DbWeight::get().writes(63_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:0)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ClosedOrders` (r:1 w:1)
+	/// Proof: `GamePallet::ClosedOrders` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OrderSweepCursor` (r:1 w:1)
+	/// Proof: `GamePallet::OrderSweepCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ClosedOrdersExpiringAt` (r:0 w:1)
+	/// Proof: `GamePallet::ClosedOrdersExpiringAt` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	fn cancel_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `570`
+		//  Estimated: `3668`
+		// Minimum execution time: 179_637_000 picoseconds.
+		Weight::from_parts(199_987_000, 3668)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `GamePallet::OrderNonces` (r:1 w:1)
+	/// Proof: `GamePallet::OrderNonces` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn bump_order_nonce() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `143`
+		//  Estimated: `3517`
+		// Minimum execution time: 24_907_000 picoseconds.
+		Weight::from_parts(27_861_000, 3517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `GamePallet::ClosedOrdersExpiringAt` (r:2 w:1)
+	/// Proof: `GamePallet::ClosedOrdersExpiringAt` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ClosedOrders` (r:0 w:1)
+	/// Proof: `GamePallet::ClosedOrders` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn expire_closed_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `459`
+		//  Estimated: `6156`
+		// Minimum execution time: 124_036_000 picoseconds.
+		Weight::from_parts(132_907_000, 6156)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `GamePallet::GameCount` (r:1 w:1)
+	/// Proof: `GamePallet::GameCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `RandomnessCollectiveFlip::RandomMaterial` (r:1 w:0)
+	/// Proof: `RandomnessCollectiveFlip::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 3089, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Games` (r:1 w:1)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::LiveGameCount` (r:1 w:1)
+	/// Proof: `GamePallet::LiveGameCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:1)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	fn issue_soulbound_game() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `284`
+		//  Estimated: `4079`
+		// Minimum execution time: 62_022_000 picoseconds.
+		Weight::from_parts(69_835_000, 4079)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 }
 
-// For backwards compatibility and tests
+// For backwards compatibility and tests.
 impl WeightInfo for () {
-	/// Storage: TemplateModule Something (r:0 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn do_something() -> Weight {
+	/// Storage: `GamePallet::GameCount` (r:1 w:1)
+	/// Proof: `GamePallet::GameCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `RandomnessCollectiveFlip::RandomMaterial` (r:1 w:0)
+	/// Proof: `RandomnessCollectiveFlip::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 3089, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Games` (r:1 w:1)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::LiveGameCount` (r:1 w:1)
+	/// Proof: `GamePallet::LiveGameCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:1)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	fn create_game() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `284`
+		//  Estimated: `4079`
+		// Minimum execution time: 44_148_000 picoseconds.
+		Weight::from_parts(64_368_000, 4079)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `GamePallet::Auctions` (r:1 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Games` (r:1 w:1)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:1 w:1)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::ListingsExpiringAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Offers` (r:1 w:1)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OffersExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:10 w:10)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:11 w:11)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:21 w:21)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Swaps` (r:5 w:5)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:1 w:0)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Approvals` (r:0 w:1)
+	/// Proof: `GamePallet::Approvals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:2)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::RentalOffers` (r:0 w:1)
+	/// Proof: `GamePallet::RentalOffers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn transfer_game() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7959`
+		//  Estimated: `54414`
+		// Minimum execution time: 1_250_786_000 picoseconds.
+		Weight::from_parts(1_746_960_000, 54414)
+			.saturating_add(RocksDbWeight::get().reads(58_u64))
+			.saturating_add(RocksDbWeight::get().writes(57_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:1)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:1 w:1)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingsExpiringAt` (r:2 w:2)
+	/// Proof: `GamePallet::ListingsExpiringAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingSweepCursor` (r:1 w:0)
+	/// Proof: `GamePallet::ListingSweepCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_game_price() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3773`
+		//  Estimated: `9166`
+		// Minimum execution time: 79_363_000 picoseconds.
+		Weight::from_parts(115_949_000, 9166)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:1)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:1)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:13 w:13)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ProtocolFee` (r:1 w:0)
+	/// Proof: `GamePallet::ProtocolFee` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:1 w:0)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Offers` (r:1 w:1)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OffersExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:11 w:11)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:21 w:21)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Swaps` (r:5 w:5)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:1 w:0)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Approvals` (r:0 w:1)
+	/// Proof: `GamePallet::Approvals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:2)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::RentalOffers` (r:0 w:1)
+	/// Proof: `GamePallet::RentalOffers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn buy_game() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8518`
+		//  Estimated: `54414`
+		// Minimum execution time: 1_439_137_000 picoseconds.
+		Weight::from_parts(2_578_003_000, 54414)
+			.saturating_add(RocksDbWeight::get().reads(61_u64))
+			.saturating_add(RocksDbWeight::get().writes(59_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:0)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:1)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::AuctionsEndingAt` (r:1 w:1)
+	/// Proof: `GamePallet::AuctionsEndingAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	fn create_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3212`
+		//  Estimated: `5078`
+		// Minimum execution time: 99_726_000 picoseconds.
+		Weight::from_parts(129_321_000, 5078)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `GamePallet::Auctions` (r:1 w:1)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	fn bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `613`
+		//  Estimated: `6254`
+		// Minimum execution time: 146_100_000 picoseconds.
+		Weight::from_parts(161_296_000, 6254)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:1)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::AuctionsEndingAt` (r:1 w:1)
+	/// Proof: `GamePallet::AuctionsEndingAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	fn cancel_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2572`
+		//  Estimated: `5078`
+		// Minimum execution time: 96_288_000 picoseconds.
+		Weight::from_parts(141_698_000, 5078)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `GamePallet::AuctionsEndingAt` (r:1 w:1)
+	/// Proof: `GamePallet::AuctionsEndingAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:1)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Games` (r:1 w:1)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:14 w:13)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ProtocolFee` (r:1 w:0)
+	/// Proof: `GamePallet::ProtocolFee` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:12 w:12)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:1 w:0)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Offers` (r:1 w:1)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OffersExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:21 w:21)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Swaps` (r:5 w:5)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:1 w:0)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Approvals` (r:0 w:1)
+	/// Proof: `GamePallet::Approvals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:2)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::RentalOffers` (r:0 w:1)
+	/// Proof: `GamePallet::RentalOffers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn settle_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `13034`
+		//  Estimated: `54414`
+		// Minimum execution time: 1_617_916_000 picoseconds.
+		Weight::from_parts(2_585_976_000, 54414)
+			.saturating_add(RocksDbWeight::get().reads(64_u64))
+			.saturating_add(RocksDbWeight::get().writes(61_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:0)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:1)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn create_dutch_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `478`
+		//  Estimated: `3668`
+		// Minimum execution time: 57_646_000 picoseconds.
+		Weight::from_parts(74_884_000, 3668)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:0)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Offers` (r:1 w:1)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OffersExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn make_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2205`
+		//  Estimated: `6678`
+		// Minimum execution time: 179_588_000 picoseconds.
+		Weight::from_parts(280_591_000, 6678)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `GamePallet::Offers` (r:1 w:1)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OffersExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	fn withdraw_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1919`
+		//  Estimated: `6678`
+		// Minimum execution time: 105_938_000 picoseconds.
+		Weight::from_parts(168_214_000, 6678)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:1)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Offers` (r:1 w:1)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OffersExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:12 w:12)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ProtocolFee` (r:1 w:0)
+	/// Proof: `GamePallet::ProtocolFee` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:11 w:11)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:1 w:0)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:21 w:21)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Swaps` (r:5 w:5)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:1 w:0)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Approvals` (r:0 w:1)
+	/// Proof: `GamePallet::Approvals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:2)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::RentalOffers` (r:0 w:1)
+	/// Proof: `GamePallet::RentalOffers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn accept_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8275`
+		//  Estimated: `54414`
+		// Minimum execution time: 1_455_489_000 picoseconds.
+		Weight::from_parts(2_378_366_000, 54414)
+			.saturating_add(RocksDbWeight::get().reads(60_u64))
+			.saturating_add(RocksDbWeight::get().writes(57_u64))
+	}
+	/// Storage: `GamePallet::OffersExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Offers` (r:50 w:50)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:50 w:50)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:50 w:50)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 50]`.
+	fn expire_offers(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `154 + n * (375 ±0)`
+		//  Estimated: `6678 + n * (3044 ±0)`
+		// Minimum execution time: 9_494_000 picoseconds.
+		Weight::from_parts(153_567_880, 6678)
+			// Standard Error: 1_642_382
+			.saturating_add(Weight::from_parts(75_263_417, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3044).saturating_mul(n.into()))
+	}
+	/// Storage: `GamePallet::ListingsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::ListingsExpiringAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Games` (r:50 w:50)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:0 w:50)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 50]`.
+	fn expire_listings(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `309 + n * (208 ±0)`
+		//  Estimated: `5078 + n * (2678 ±0)`
+		// Minimum execution time: 5_516_000 picoseconds.
+		Weight::from_parts(5_890_000, 5078)
+			// Standard Error: 165_778
+			.saturating_add(Weight::from_parts(21_879_929, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2678).saturating_mul(n.into()))
+	}
+	/// Storage: `GamePallet::ProtocolFee` (r:0 w:1)
+	/// Proof: `GamePallet::ProtocolFee` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_protocol_fee() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+		// Minimum execution time: 9_119_000 picoseconds.
+		Weight::from_parts(10_055_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: TemplateModule Something (r:1 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
+	/// Storage: `GamePallet::Games` (r:1 w:0)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:1 w:1)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	fn set_metadata() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+		//  Measured:  `5370`
+		//  Estimated: `7983`
+		// Minimum execution time: 171_105_000 picoseconds.
+		Weight::from_parts(178_510_000, 7983)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:0)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:1 w:1)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	fn clear_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5200`
+		//  Estimated: `7983`
+		// Minimum execution time: 102_602_000 picoseconds.
+		Weight::from_parts(107_854_000, 7983)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:0)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:1 w:1)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	fn lock_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5070`
+		//  Estimated: `7983`
+		// Minimum execution time: 68_227_000 picoseconds.
+		Weight::from_parts(77_186_000, 7983)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:0)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Operators` (r:1 w:0)
+	/// Proof: `GamePallet::Operators` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Approvals` (r:0 w:1)
+	/// Proof: `GamePallet::Approvals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn approve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `541`
+		//  Estimated: `3668`
+		// Minimum execution time: 45_226_000 picoseconds.
+		Weight::from_parts(47_125_000, 3668)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `GamePallet::Operators` (r:0 w:1)
+	/// Proof: `GamePallet::Operators` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	fn set_operator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 17_035_000 picoseconds.
+		Weight::from_parts(17_921_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `GamePallet::Approvals` (r:1 w:1)
+	/// Proof: `GamePallet::Approvals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Operators` (r:1 w:0)
+	/// Proof: `GamePallet::Operators` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Games` (r:1 w:1)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:1 w:1)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::ListingsExpiringAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Offers` (r:1 w:1)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OffersExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:11 w:11)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:11 w:11)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:21 w:21)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Swaps` (r:5 w:5)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:1 w:0)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:2)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::RentalOffers` (r:0 w:1)
+	/// Proof: `GamePallet::RentalOffers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn transfer_from() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8330`
+		//  Estimated: `54414`
+		// Minimum execution time: 1_859_928_000 picoseconds.
+		Weight::from_parts(2_074_784_000, 54414)
+			.saturating_add(RocksDbWeight::get().reads(61_u64))
+			.saturating_add(RocksDbWeight::get().writes(58_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:1)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Approvals` (r:1 w:1)
+	/// Proof: `GamePallet::Approvals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Operators` (r:1 w:0)
+	/// Proof: `GamePallet::Operators` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::LiveGameCount` (r:1 w:1)
+	/// Proof: `GamePallet::LiveGameCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:1 w:0)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Offers` (r:1 w:1)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OffersExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:11 w:11)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:11 w:11)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:21 w:21)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Swaps` (r:5 w:5)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:1 w:1)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:1)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::RentalOffers` (r:0 w:1)
+	/// Proof: `GamePallet::RentalOffers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn burn_game() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `12892`
+		//  Estimated: `54414`
+		// Minimum execution time: 1_278_134_000 picoseconds.
+		Weight::from_parts(2_086_410_000, 54414)
+			.saturating_add(RocksDbWeight::get().reads(61_u64))
+			.saturating_add(RocksDbWeight::get().writes(57_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:1)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:1 w:1)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::ListingsExpiringAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::RentalOffers` (r:0 w:1)
+	/// Proof: `GamePallet::RentalOffers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn lend_game() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2180`
+		//  Estimated: `5078`
+		// Minimum execution time: 89_019_000 picoseconds.
+		Weight::from_parts(95_604_000, 5078)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `GamePallet::RentalOffers` (r:1 w:1)
+	/// Proof: `GamePallet::RentalOffers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Games` (r:1 w:1)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:1 w:1)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::ListingsExpiringAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	fn accept_rental() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2304`
+		//  Estimated: `5078`
+		// Minimum execution time: 196_279_000 picoseconds.
+		Weight::from_parts(206_805_000, 5078)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `GamePallet::GameCount` (r:1 w:1)
+	/// Proof: `GamePallet::GameCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `RandomnessCollectiveFlip::RandomMaterial` (r:1 w:0)
+	/// Proof: `RandomnessCollectiveFlip::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 3089, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Games` (r:50 w:50)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::LiveGameCount` (r:1 w:1)
+	/// Proof: `GamePallet::LiveGameCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:50)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 50]`.
+	fn create_games(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `369 + n * (1 ±0)`
+		//  Estimated: `4079 + n * (2678 ±0)`
+		// Minimum execution time: 60_400_000 picoseconds.
+		Weight::from_parts(5_353_374, 4079)
+			// Standard Error: 163_306
+			.saturating_add(Weight::from_parts(31_412_115, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2678).saturating_mul(n.into()))
+	}
+	/// Storage: `GamePallet::Auctions` (r:50 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:50 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:50 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:50 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Games` (r:50 w:50)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:50 w:50)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingsExpiringAt` (r:50 w:50)
+	/// Proof: `GamePallet::ListingsExpiringAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Offers` (r:50 w:50)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OffersExpiringAt` (r:50 w:50)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:10 w:10)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:11 w:11)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:1050 w:1050)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Swaps` (r:250 w:250)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:50 w:50)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:50 w:0)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Approvals` (r:0 w:50)
+	/// Proof: `GamePallet::Approvals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:100)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::RentalOffers` (r:0 w:50)
+	/// Proof: `GamePallet::RentalOffers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 50]`.
+	fn transfer_games(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3931 + n * (5272 ±0)`
+		//  Estimated: `29942 + n * (53424 ±0)`
+		// Minimum execution time: 1_909_867_000 picoseconds.
+		Weight::from_parts(1_970_096_000, 29942)
+			// Standard Error: 16_706_557
+			.saturating_add(Weight::from_parts(1_770_806_207, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(21_u64))
+			.saturating_add(RocksDbWeight::get().reads((37_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(21_u64))
+			.saturating_add(RocksDbWeight::get().writes((36_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 53424).saturating_mul(n.into()))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:0)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:1)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctionsEndingAt` (r:1 w:1)
+	/// Proof: `GamePallet::SealedAuctionsEndingAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	fn create_sealed_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3725`
+		//  Estimated: `5078`
+		// Minimum execution time: 98_904_000 picoseconds.
+		Weight::from_parts(108_368_000, 5078)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedBids` (r:1 w:1)
+	/// Proof: `GamePallet::SealedBids` (`max_values`: None, `max_size`: Some(1669), added: 4144, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	fn commit_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2369`
+		//  Estimated: `5134`
+		// Minimum execution time: 111_337_000 picoseconds.
+		Weight::from_parts(126_619_000, 5134)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:1)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedBids` (r:1 w:1)
+	/// Proof: `GamePallet::SealedBids` (`max_values`: None, `max_size`: Some(1669), added: 4144, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	fn reveal_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2777`
+		//  Estimated: `5134`
+		// Minimum execution time: 99_858_000 picoseconds.
+		Weight::from_parts(110_744_000, 5134)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `GamePallet::SealedAuctionsEndingAt` (r:1 w:1)
+	/// Proof: `GamePallet::SealedAuctionsEndingAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:1)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedBids` (r:1 w:1)
+	/// Proof: `GamePallet::SealedBids` (`max_values`: None, `max_size`: Some(1669), added: 4144, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:33 w:32)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:31 w:31)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Games` (r:1 w:1)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ProtocolFee` (r:1 w:0)
+	/// Proof: `GamePallet::ProtocolFee` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:1 w:0)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Offers` (r:1 w:1)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OffersExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:21 w:21)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Swaps` (r:5 w:5)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:1 w:0)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Approvals` (r:0 w:1)
+	/// Proof: `GamePallet::Approvals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:2)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::RentalOffers` (r:0 w:1)
+	/// Proof: `GamePallet::RentalOffers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn settle_sealed_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `17461`
+		//  Estimated: `86889`
+		// Minimum execution time: 2_208_569_000 picoseconds.
+		Weight::from_parts(2_968_890_000, 86889)
+			.saturating_add(RocksDbWeight::get().reads(103_u64))
+			.saturating_add(RocksDbWeight::get().writes(100_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:10 w:0)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::NextSwapId` (r:1 w:1)
+	/// Proof: `GamePallet::NextSwapId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:5 w:5)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Swaps` (r:0 w:1)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 10]`.
+	fn propose_swap(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `715 + n * (197 ±0)`
+		//  Estimated: `4599 + n * (2678 ±0)`
+		// Minimum execution time: 107_277_000 picoseconds.
+		Weight::from_parts(145_502_363, 4599)
+			// Standard Error: 583_902
+			.saturating_add(Weight::from_parts(23_517_949, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(Weight::from_parts(0, 2678).saturating_mul(n.into()))
+	}
+	/// Storage: `GamePallet::Swaps` (r:41 w:41)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:170 w:170)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:41 w:41)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:11 w:11)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:12 w:12)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:10 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:10 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:10 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:10 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Games` (r:10 w:10)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:10 w:0)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Offers` (r:10 w:10)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OffersExpiringAt` (r:10 w:10)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:10 w:0)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Approvals` (r:0 w:10)
+	/// Proof: `GamePallet::Approvals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:20)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::RentalOffers` (r:0 w:10)
+	/// Proof: `GamePallet::RentalOffers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn accept_swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `48620`
+		//  Estimated: `433470`
+		// Minimum execution time: 17_599_172_000 picoseconds.
+		Weight::from_parts(18_876_450_000, 433470)
+			.saturating_add(RocksDbWeight::get().reads(365_u64))
+			.saturating_add(RocksDbWeight::get().writes(345_u64))
+	}
+	/// Storage: `GamePallet::Swaps` (r:1 w:1)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:5 w:5)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	fn cancel_swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1297`
+		//  Estimated: `13710`
+		// Minimum execution time: 211_046_000 picoseconds.
+		Weight::from_parts(230_168_000, 13710)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Swaps` (r:50 w:50)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:250 w:250)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:50 w:50)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:50 w:50)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 50]`.
+	fn expire_swaps(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `232 + n * (926 ±0)`
+		//  Estimated: `3678 + n * (12720 ±0)`
+		// Minimum execution time: 8_135_000 picoseconds.
+		Weight::from_parts(8_867_000, 3678)
+			// Standard Error: 1_551_468
+			.saturating_add(Weight::from_parts(174_919_705, 0).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(n.into())))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((8_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 12720).saturating_mul(n.into()))
+	}
+	/// Storage: `GamePallet::Games` (r:10 w:10)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:10 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:10 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:10 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:10 w:10)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:10 w:10)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::ListingsExpiringAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::NextBundleId` (r:1 w:1)
+	/// Proof: `GamePallet::NextBundleId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Bundles` (r:0 w:1)
+	/// Proof: `GamePallet::Bundles` (`max_values`: None, `max_size`: Some(381), added: 2856, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 10]`.
+	fn create_bundle(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `347 + n * (265 ±0)`
+		//  Estimated: `5078 + n * (2678 ±0)`
+		// Minimum execution time: 123_151_000 picoseconds.
+		Weight::from_parts(66_030_506, 5078)
+			// Standard Error: 739_743
+			.saturating_add(Weight::from_parts(93_644_361, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2678).saturating_mul(n.into()))
+	}
+	/// Storage: `GamePallet::Bundles` (r:1 w:1)
+	/// Proof: `GamePallet::Bundles` (`max_values`: None, `max_size`: Some(381), added: 2856, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:0 w:10)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn cancel_bundle() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `557`
+		//  Estimated: `3846`
+		// Minimum execution time: 92_493_000 picoseconds.
+		Weight::from_parts(119_241_000, 3846)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: `GamePallet::Bundles` (r:1 w:1)
+	/// Proof: `GamePallet::Bundles` (`max_values`: None, `max_size`: Some(381), added: 2856, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Games` (r:10 w:10)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ProtocolFee` (r:1 w:0)
+	/// Proof: `GamePallet::ProtocolFee` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:22 w:22)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:10 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:10 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:10 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:10 w:0)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Offers` (r:10 w:10)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OffersExpiringAt` (r:10 w:10)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:11 w:11)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:210 w:210)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Swaps` (r:50 w:50)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:10 w:10)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:10 w:0)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Approvals` (r:0 w:10)
+	/// Proof: `GamePallet::Approvals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:0 w:10)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:20)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::RentalOffers` (r:0 w:10)
+	/// Proof: `GamePallet::RentalOffers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn buy_bundle() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `55718`
+		//  Estimated: `535230`
+		// Minimum execution time: 11_669_623_000 picoseconds.
+		Weight::from_parts(15_537_662_000, 535230)
+			.saturating_add(RocksDbWeight::get().reads(385_u64))
+			.saturating_add(RocksDbWeight::get().writes(384_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:1)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OrderNonces` (r:1 w:0)
+	/// Proof: `GamePallet::OrderNonces` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ClosedOrders` (r:1 w:1)
+	/// Proof: `GamePallet::ClosedOrders` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OrderSweepCursor` (r:1 w:1)
+	/// Proof: `GamePallet::OrderSweepCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:13 w:13)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ProtocolFee` (r:1 w:0)
+	/// Proof: `GamePallet::ProtocolFee` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Auctions` (r:1 w:0)
+	/// Proof: `GamePallet::Auctions` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::DutchAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::DutchAuctions` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SealedAuctions` (r:1 w:0)
+	/// Proof: `GamePallet::SealedAuctions` (`max_values`: None, `max_size`: Some(153), added: 2628, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::BundledGames` (r:1 w:0)
+	/// Proof: `GamePallet::BundledGames` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingExpiry` (r:1 w:1)
+	/// Proof: `GamePallet::ListingExpiry` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ListingsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::ListingsExpiringAt` (`max_values`: None, `max_size`: Some(1613), added: 4088, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Offers` (r:1 w:1)
+	/// Proof: `GamePallet::Offers` (`max_values`: None, `max_size`: Some(569), added: 3044, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OffersExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::OffersExpiringAt` (`max_values`: None, `max_size`: Some(3213), added: 5688, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:11 w:11)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsByGame` (r:21 w:21)
+	/// Proof: `GamePallet::SwapsByGame` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Swaps` (r:5 w:5)
+	/// Proof: `GamePallet::Swaps` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::SwapsExpiringAt` (r:1 w:1)
+	/// Proof: `GamePallet::SwapsExpiringAt` (`max_values`: None, `max_size`: Some(213), added: 2688, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GameMetadata` (r:1 w:0)
+	/// Proof: `GamePallet::GameMetadata` (`max_values`: None, `max_size`: Some(4518), added: 6993, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ClosedOrdersExpiringAt` (r:0 w:1)
+	/// Proof: `GamePallet::ClosedOrdersExpiringAt` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Approvals` (r:0 w:1)
+	/// Proof: `GamePallet::Approvals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:2)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::RentalOffers` (r:0 w:1)
+	/// Proof: `GamePallet::RentalOffers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn fill_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8455`
+		//  Estimated: `54414`
+		// Minimum execution time: 2_431_608_000 picoseconds.
+		Weight::from_parts(2_701_319_000, 54414)
+			.saturating_add(RocksDbWeight::get().reads(66_u64))
+			.saturating_add(RocksDbWeight::get().writes(63_u64))
+	}
+	/// Storage: `GamePallet::Games` (r:1 w:0)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ClosedOrders` (r:1 w:1)
+	/// Proof: `GamePallet::ClosedOrders` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::OrderSweepCursor` (r:1 w:1)
+	/// Proof: `GamePallet::OrderSweepCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ClosedOrdersExpiringAt` (r:0 w:1)
+	/// Proof: `GamePallet::ClosedOrdersExpiringAt` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	fn cancel_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `570`
+		//  Estimated: `3668`
+		// Minimum execution time: 179_637_000 picoseconds.
+		Weight::from_parts(199_987_000, 3668)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `GamePallet::OrderNonces` (r:1 w:1)
+	/// Proof: `GamePallet::OrderNonces` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn bump_order_nonce() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `143`
+		//  Estimated: `3517`
+		// Minimum execution time: 24_907_000 picoseconds.
+		Weight::from_parts(27_861_000, 3517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `GamePallet::ClosedOrdersExpiringAt` (r:2 w:1)
+	/// Proof: `GamePallet::ClosedOrdersExpiringAt` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::ClosedOrders` (r:0 w:1)
+	/// Proof: `GamePallet::ClosedOrders` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn expire_closed_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `459`
+		//  Estimated: `6156`
+		// Minimum execution time: 124_036_000 picoseconds.
+		Weight::from_parts(132_907_000, 6156)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `GamePallet::GameCount` (r:1 w:1)
+	/// Proof: `GamePallet::GameCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `RandomnessCollectiveFlip::RandomMaterial` (r:1 w:0)
+	/// Proof: `RandomnessCollectiveFlip::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 3089, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::Games` (r:1 w:1)
+	/// Proof: `GamePallet::Games` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::LiveGameCount` (r:1 w:1)
+	/// Proof: `GamePallet::LiveGameCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GamePallet::GamesOwnedBy` (r:0 w:1)
+	/// Proof: `GamePallet::GamesOwnedBy` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	fn issue_soulbound_game() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `284`
+		//  Estimated: `4079`
+		// Minimum execution time: 62_022_000 picoseconds.
+		Weight::from_parts(69_835_000, 4079)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 }
@@ -42,8 +42,8 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-	AccountId, Aura, Balance, Block, Executive, Grandpa, InherentDataExt, Nonce, Runtime,
-	RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, VERSION,
+	AccountId, Aura, Balance, Block, Executive, GamePallet, Grandpa, InherentDataExt, Nonce,
+	Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, VERSION,
 };
 
 impl_runtime_apis! {
@@ -170,6 +170,47 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(4)]
+	impl pallet_template_runtime_api::GameMarketplaceApi<Block, AccountId, Balance> for Runtime {
+		fn current_price(id: [u8; 32]) -> Option<Balance> {
+			GamePallet::current_price(id)
+		}
+
+		fn game(id: [u8; 32]) -> Option<pallet_template_runtime_api::GameInfo<AccountId, Balance>> {
+			GamePallet::game_info(id)
+		}
+
+		fn games_owned_by(
+			owner: AccountId,
+			offset: u32,
+			limit: u32,
+		) -> Vec<pallet_template_runtime_api::GameInfo<AccountId, Balance>> {
+			GamePallet::games_of(owner, offset, limit)
+		}
+
+		fn listings(
+			offset: u32,
+			limit: u32,
+		) -> Vec<pallet_template_runtime_api::GameInfo<AccountId, Balance>> {
+			GamePallet::listings(offset, limit)
+		}
+
+		fn supply() -> pallet_template_runtime_api::Supply {
+			GamePallet::supply()
+		}
+
+		fn current_user(id: [u8; 32]) -> Option<AccountId> {
+			GamePallet::current_user(id)
+		}
+
+		fn listings_from(
+			start: Option<[u8; 32]>,
+			limit: u32,
+		) -> pallet_template_runtime_api::Listings<AccountId, Balance> {
+			GamePallet::listings_from(start, limit)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,
@@ -31,18 +31,23 @@ use frame_support::{
 		constants::{RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND},
 		IdentityFee, Weight,
 	},
+	PalletId,
 };
 use frame_system::limits::{BlockLength, BlockWeights};
 use pallet_transaction_payment::{ConstFeeMultiplier, FungibleAdapter, Multiplier};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_runtime::{traits::One, Perbill};
+use sp_runtime::{
+	traits::{AccountIdConversion, One, Verify},
+	Perbill, Permill,
+};
 use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-	AccountId, Aura, Balance, Balances, Block, BlockNumber, Hash, Nonce, PalletInfo, Runtime,
-	RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask,
-	System, EXISTENTIAL_DEPOSIT, SLOT_DURATION, VERSION,
+	AccountId, Aura, Balance, Balances, Block, BlockNumber, Hash, Nonce, PalletInfo,
+	RandomnessCollectiveFlip, Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason,
+	RuntimeHoldReason, RuntimeOrigin, RuntimeTask, Signature, System, DAYS, EXISTENTIAL_DEPOSIT,
+	MICRO_UNIT, MILLI_UNIT, SLOT_DURATION, VERSION,
 };
 
 const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
@@ -155,10 +160,64 @@ impl pallet_sudo::Config for Runtime {
 	type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
 }
 
+impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
+
+parameter_types! {
+	pub const MaxRoyalty: Permill = Permill::from_percent(25);
+	pub const DefaultProtocolFee: Permill = Permill::from_percent(2);
+	pub const MarketplacePalletId: PalletId = PalletId(*b"py/games");
+	pub MarketplaceFeeAccount: AccountId = MarketplacePalletId::get().into_account_truncating();
+	pub const MetadataDepositBase: Balance = 10 * MILLI_UNIT;
+	pub const MetadataDepositPerByte: Balance = 10 * MICRO_UNIT;
+	pub const MinOffer: Balance = 10 * MILLI_UNIT;
+	pub const MaxOfferDuration: BlockNumber = 30 * DAYS;
+	pub const OrderCancellationDeposit: Balance = 10 * MILLI_UNIT;
+	pub const SwapDeposit: Balance = 10 * MILLI_UNIT;
+	pub const MaxSwapDuration: BlockNumber = 30 * DAYS;
+	// Auctions that do not fit in a quarter of the block are settled in the next ones.
+	pub MaxSettlementWeight: Weight = Perbill::from_percent(25) * RuntimeBlockWeights::get().max_block;
+	// Committing without revealing costs the deposit, so bids cannot be used to stall auctions.
+	pub const SealedBidPolicy: pallet_template::UnrevealedBidPolicy =
+		pallet_template::UnrevealedBidPolicy::Slash;
+}
+
 /// Configure the pallet-template in pallets/template.
 impl pallet_template::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;
+	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Randomness = RandomnessCollectiveFlip;
+	type MaxAuctionsPerBlock = ConstU32<50>;
+	type MaxSettlementWeight = MaxSettlementWeight;
+	type MaxSealedBids = ConstU32<20>;
+	type UnrevealedBidPolicy = SealedBidPolicy;
+	type MaxOffersPerGame = ConstU32<10>;
+	type MaxOffersPerBlock = ConstU32<50>;
+	type MinOffer = MinOffer;
+	type MaxOfferDuration = MaxOfferDuration;
+	type MaxListingsPerBlock = ConstU32<50>;
+	type MaxListingsScanned = ConstU32<10_000>;
+	type MaxSwapGames = ConstU32<5>;
+	type MaxSwapsPerGame = ConstU32<5>;
+	type MaxSwapsPerBlock = ConstU32<50>;
+	type SwapDeposit = SwapDeposit;
+	type MaxSwapDuration = MaxSwapDuration;
+	type MaxBundleSize = ConstU32<10>;
+	type MaxBatchSize = ConstU32<50>;
+	type MaxRoyalty = MaxRoyalty;
+	type DefaultProtocolFee = DefaultProtocolFee;
+	type FeeDestination = MarketplaceFeeAccount;
+	type StringLimit = ConstU32<128>;
+	type MaxAttributes = ConstU32<16>;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type IssuerOrigin = frame_system::EnsureRoot<AccountId>;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as Verify>::Signer;
+	type OrderCancellationDeposit = OrderCancellationDeposit;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 /// Configure the pallet-benchmark-example in pallets/benchmark-example.
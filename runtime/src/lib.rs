@@ -67,10 +67,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: apis::RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -168,7 +168,13 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+	pallet_template::migrations::v1::MigrateV0ToV1<Runtime>,
+	pallet_template::migrations::v2::MigrateV1ToV2<Runtime>,
+	pallet_template::migrations::v3::MigrateV2ToV3<Runtime>,
+	pallet_template::migrations::v4::MigrateV3ToV4<Runtime>,
+	pallet_template::migrations::v5::MigrateV4ToV5<Runtime>,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -223,4 +229,7 @@ mod runtime {
 
 	#[runtime::pallet_index(8)]
 	pub type BenchmarkExample = pallet_benchmark_example;
+
+	#[runtime::pallet_index(9)]
+	pub type RandomnessCollectiveFlip = pallet_insecure_randomness_collective_flip;
 }